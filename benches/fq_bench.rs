@@ -44,8 +44,68 @@ fn bench_invert_nonzero(bencher: &mut Bencher) {
     bencher.iter(move || n.invert_nonzero());
 }
 
+#[bench]
+fn bench_invert_vartime(bencher: &mut Bencher) {
+    let n = Fq::from(7).invert_nonzero();
+    bencher.iter(move || n.invert_vartime());
+}
+
 #[bench]
 fn bench_sqrt_vartime(bencher: &mut Bencher) {
     let n = Fq::one().double().double();
     bencher.iter(move || n.sqrt_vartime());
 }
+
+#[bench]
+fn bench_sqrt(bencher: &mut Bencher) {
+    let n = Fq::one().double().double();
+    bencher.iter(move || n.sqrt());
+}
+
+#[bench]
+fn bench_sqrt_ratio(bencher: &mut Bencher) {
+    let u = Fq::one().double().double();
+    let v = Fq::one().double();
+    bencher.iter(move || Fq::sqrt_ratio(&u, &v));
+}
+
+#[bench]
+fn bench_legendre(bencher: &mut Bencher) {
+    let n = Fq::from(7).invert_nonzero();
+    bencher.iter(move || n.legendre());
+}
+
+#[bench]
+fn bench_legendre_vartime(bencher: &mut Bencher) {
+    let n = Fq::from(7).invert_nonzero();
+    bencher.iter(move || n.legendre_vartime());
+}
+
+#[bench]
+fn bench_ntt_2_16(bencher: &mut Bencher) {
+    let domain = EvaluationDomain::<Fq>::new(16).unwrap();
+    let mut values: Vec<Fq> = (0..domain.size()).map(Fq::from).collect();
+    bencher.iter(move || {
+        domain.ntt(&mut values);
+    });
+}
+
+#[bench]
+fn bench_sum_of_products_16(bencher: &mut Bencher) {
+    let a: Vec<Fq> = (0..16).map(|i| -Fq::from(i)).collect();
+    let b: Vec<Fq> = (0..16).map(|i| Fq::from(i).square()).collect();
+    bencher.iter(|| Fq::sum_of_products(&a, &b));
+}
+
+#[bench]
+fn bench_sum_of_products_16_naive(bencher: &mut Bencher) {
+    let a: Vec<Fq> = (0..16).map(|i| -Fq::from(i)).collect();
+    let b: Vec<Fq> = (0..16).map(|i| Fq::from(i).square()).collect();
+    bencher.iter(|| {
+        let mut acc = Fq::zero();
+        for (a, b) in a.iter().zip(b.iter()) {
+            acc += a * b;
+        }
+        acc
+    });
+}
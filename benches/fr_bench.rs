@@ -44,8 +44,40 @@ fn bench_invert_nonzero(bencher: &mut Bencher) {
     bencher.iter(move || n.invert_nonzero());
 }
 
+#[bench]
+fn bench_invert_vartime(bencher: &mut Bencher) {
+    let n = Fr::from(7).invert_nonzero();
+    bencher.iter(move || n.invert_vartime());
+}
+
 #[bench]
 fn bench_sqrt_vartime(bencher: &mut Bencher) {
     let n = Fr::one().double().double();
     bencher.iter(move || n.sqrt_vartime());
 }
+
+#[bench]
+fn bench_sqrt(bencher: &mut Bencher) {
+    let n = Fr::one().double().double();
+    bencher.iter(move || n.sqrt());
+}
+
+#[bench]
+fn bench_sum_of_products_16(bencher: &mut Bencher) {
+    let a: Vec<Fr> = (0..16).map(|i| -Fr::from(i)).collect();
+    let b: Vec<Fr> = (0..16).map(|i| Fr::from(i).square()).collect();
+    bencher.iter(|| Fr::sum_of_products(&a, &b));
+}
+
+#[bench]
+fn bench_sum_of_products_16_naive(bencher: &mut Bencher) {
+    let a: Vec<Fr> = (0..16).map(|i| -Fr::from(i)).collect();
+    let b: Vec<Fr> = (0..16).map(|i| Fr::from(i).square()).collect();
+    bencher.iter(|| {
+        let mut acc = Fr::zero();
+        for (a, b) in a.iter().zip(b.iter()) {
+            acc += a * b;
+        }
+        acc
+    });
+}
@@ -56,3 +56,199 @@ fn bench_cached_affine_point_addition(bencher: &mut Bencher) {
     let b = AffinePoint::identity().to_niels();
     bencher.iter(move || &a + &b);
 }
+
+#[bench]
+fn bench_point_is_torsion_free(bencher: &mut Bencher) {
+    let a = ExtendedPoint::identity();
+    bencher.iter(move || a.is_torsion_free());
+}
+
+// Serialization
+
+#[bench]
+fn bench_point_from_bytes(bencher: &mut Bencher) {
+    let bytes = AffinePoint::identity().into_bytes();
+    bencher.iter(move || AffinePoint::from_bytes(bytes));
+}
+
+#[bench]
+fn bench_point_from_bytes_vartime(bencher: &mut Bencher) {
+    let bytes = AffinePoint::identity().into_bytes();
+    bencher.iter(move || AffinePoint::from_bytes_vartime(bytes));
+}
+
+// Scalar multiplication
+
+#[bench]
+fn bench_point_mul(bencher: &mut Bencher) {
+    let a = ExtendedPoint::identity();
+    let s = -Fr::from(0x1234_5678);
+    bencher.iter(move || a * s);
+}
+
+#[bench]
+fn bench_basepoint_table_mul(bencher: &mut Bencher) {
+    let table = BasepointTable::new(&ExtendedPoint::identity());
+    let s = -Fr::from(0x1234_5678);
+    bencher.iter(|| &table * &s);
+}
+
+#[bench]
+fn bench_basepoint_table_new(bencher: &mut Bencher) {
+    let a = ExtendedPoint::identity();
+    bencher.iter(move || BasepointTable::new(&a));
+}
+
+#[bench]
+fn bench_point_mul_vartime(bencher: &mut Bencher) {
+    let a = ExtendedPoint::identity();
+    let s = -Fr::from(0x1234_5678);
+    bencher.iter(move || a.mul_vartime(&s));
+}
+
+#[bench]
+fn bench_lookup_table_mul(bencher: &mut Bencher) {
+    let table = LookupTable::new(&ExtendedPoint::identity());
+    let s = -Fr::from(0x1234_5678);
+    bencher.iter(|| &table * &s);
+}
+
+#[bench]
+fn bench_lookup_table_from_bytes(bencher: &mut Bencher) {
+    let bytes = LookupTable::new(&ExtendedPoint::identity()).into_bytes();
+    bencher.iter(|| LookupTable::from_bytes(&bytes));
+}
+
+#[bench]
+fn bench_vartime_double_scalar_mul(bencher: &mut Bencher) {
+    let table = BasepointTable::new(&ExtendedPoint::identity());
+    let a = ExtendedPoint::identity();
+    let s = -Fr::from(0x1234_5678);
+    let c = -Fr::from(0x8765_4321);
+    bencher.iter(|| ExtendedPoint::vartime_double_scalar_mul(&c, &a, &s, &table));
+}
+
+#[bench]
+fn bench_vartime_double_scalar_mul_naive(bencher: &mut Bencher) {
+    let b = ExtendedPoint::identity();
+    let a = ExtendedPoint::identity();
+    let s = -Fr::from(0x1234_5678);
+    let c = -Fr::from(0x8765_4321);
+    bencher.iter(|| a * c + b * s);
+}
+
+// Multi-scalar multiplication
+
+fn msm_terms(n: usize) -> (Vec<Fr>, Vec<ExtendedPoint>) {
+    // Find some point on the curve other than the identity.
+    let mut bytes = [0u8; 32];
+    let base = loop {
+        bytes[0] += 1;
+        if let Some(p) = AffinePoint::from_bytes_vartime(bytes) {
+            break ExtendedPoint::from(p);
+        }
+    };
+
+    let mut scalar = -Fr::one();
+    let mut point = base;
+    let mut scalars = vec![];
+    let mut points = vec![];
+    for _ in 0..n {
+        scalars.push(scalar);
+        points.push(point);
+        scalar = scalar.square() + Fr::one();
+        point = point.double() + base;
+    }
+
+    (scalars, points)
+}
+
+#[bench]
+fn bench_multiscalar_mul_64(bencher: &mut Bencher) {
+    let (scalars, points) = msm_terms(64);
+    bencher.iter(|| ExtendedPoint::multiscalar_mul(&scalars, &points));
+}
+
+#[bench]
+fn bench_multiscalar_mul_64_naive(bencher: &mut Bencher) {
+    let (scalars, points) = msm_terms(64);
+    bencher.iter(|| {
+        let mut acc = ExtendedPoint::identity();
+        for (scalar, point) in scalars.iter().zip(points.iter()) {
+            acc = acc + point * scalar;
+        }
+        acc
+    });
+}
+
+#[bench]
+fn bench_fixed_base_msm_64(bencher: &mut Bencher) {
+    let (scalars, points) = msm_terms(64);
+    let msm = FixedBaseMsm::new(&points);
+    bencher.iter(|| msm.multiscalar_mul(&scalars));
+}
+
+#[bench]
+fn bench_fixed_base_msm_vartime_64(bencher: &mut Bencher) {
+    let (scalars, points) = msm_terms(64);
+    let msm = FixedBaseMsm::new(&points);
+    bencher.iter(|| msm.multiscalar_mul_vartime(&scalars));
+}
+
+#[bench]
+fn bench_multiscalar_mul_vartime_64(bencher: &mut Bencher) {
+    let (scalars, points) = msm_terms(64);
+    bencher.iter(|| ExtendedPoint::multiscalar_mul_vartime(&scalars, &points));
+}
+
+#[bench]
+fn bench_multiscalar_mul_vartime_1024(bencher: &mut Bencher) {
+    let (scalars, points) = msm_terms(1024);
+    bencher.iter(|| ExtendedPoint::multiscalar_mul_vartime(&scalars, &points));
+}
+
+#[bench]
+fn bench_multiscalar_mul_vartime_1024_naive(bencher: &mut Bencher) {
+    let (scalars, points) = msm_terms(1024);
+    bencher.iter(|| {
+        let mut acc = ExtendedPoint::identity();
+        for (scalar, point) in scalars.iter().zip(points.iter()) {
+            acc = acc + point * scalar;
+        }
+        acc
+    });
+}
+
+#[bench]
+fn bench_multiscalar_mul_vartime_4096(bencher: &mut Bencher) {
+    let (scalars, points) = msm_terms(4096);
+    bencher.iter(|| ExtendedPoint::multiscalar_mul_vartime(&scalars, &points));
+}
+
+#[cfg(feature = "parallel")]
+#[bench]
+fn bench_multiscalar_mul_vartime_parallel_4096(bencher: &mut Bencher) {
+    let (scalars, points) = msm_terms(4096);
+    bencher.iter(|| ExtendedPoint::multiscalar_mul_vartime_parallel(&scalars, &points));
+}
+
+// Normalization
+
+#[bench]
+fn bench_batch_normalize_65536(bencher: &mut Bencher) {
+    let (_, points) = msm_terms(65536);
+    bencher.iter(|| {
+        let mut points = points.clone();
+        batch_normalize(&mut points).count()
+    });
+}
+
+#[cfg(feature = "parallel")]
+#[bench]
+fn bench_batch_normalize_parallel_65536(bencher: &mut Bencher) {
+    let (_, points) = msm_terms(65536);
+    bencher.iter(|| {
+        let mut points = points.clone();
+        batch_normalize_parallel(&mut points).count()
+    });
+}
@@ -0,0 +1,124 @@
+#!/usr/bin/env python3
+#
+# Derives the standard Jubjub generator and prints src/generator_table.rs,
+# the precomputed BasepointTable for it.
+#
+#     python3 doc/derive/generator.py > src/generator_table.rs
+#     rustfmt --edition 2018 src/generator_table.rs
+
+q = 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001
+r = 0x0e7db4ea6533afa906673b0101343b00a6682093ccc81082d0970e5ed6f72cb7
+d = -10240 * pow(10241, q - 2, q) % q
+
+IDENTITY = (0, 1)
+
+
+def add(p, s):
+    (u1, v1), (u2, v2) = p, s
+    c = d * u1 * u2 * v1 * v2 % q
+    u = (u1 * v2 + v1 * u2) * pow(1 + c, q - 2, q) % q
+    v = (v1 * v2 + u1 * u2) * pow(1 - c, q - 2, q) % q
+    return (u, v)
+
+
+def mul(k, p):
+    acc = IDENTITY
+    while k:
+        if k & 1:
+            acc = add(acc, p)
+        p = add(p, p)
+        k >>= 1
+    return acc
+
+
+def sqrt(a):
+    # Tonelli-Shanks, as q - 1 = 2^32 t.
+    if pow(a, (q - 1) // 2, q) != 1:
+        return None
+    t, s = q - 1, 0
+    while t % 2 == 0:
+        t, s = t // 2, s + 1
+    z = 2
+    while pow(z, (q - 1) // 2, q) != q - 1:
+        z += 1
+    m, c, x, b = s, pow(z, t, q), pow(a, (t + 1) // 2, q), pow(a, t, q)
+    while b != 1:
+        i, b2 = 1, b * b % q
+        while b2 != 1:
+            i, b2 = i + 1, b2 * b2 % q
+        e = pow(c, 1 << (m - i - 1), q)
+        m, c = i, e * e % q
+        x, b = x * e % q, b * c % q
+    return x
+
+
+# The full generator is the point of order 8r with the smallest v coordinate
+# and even u, and the standard generator of the prime-order subgroup is 8
+# times it.
+v = 0
+while True:
+    v += 1
+    u = sqrt((1 - v * v) * pow(-1 - d * v * v, q - 2, q) % q)
+    if u is None:
+        continue
+    full = (u if u % 2 == 0 else q - u, v)
+    if mul(8, full) != IDENTITY and mul(4 * r, full) != IDENTITY:
+        break
+
+assert mul(8 * r, full) == IDENTITY
+generator = mul(8, full)
+
+
+def limbs(x):
+    x = x * 2**256 % q
+    return "[%s]" % ", ".join("0x%016x" % ((x >> (64 * i)) % 2**64) for i in range(4))
+
+
+def niels(p, indent):
+    u, v = p
+    pad = " " * indent
+    lines = ["niels("] + ["    %s," % limbs(x) for x in (v + u, v - u, 2 * d * u * v)] + ["),"]
+    return "\n".join(pad + line for line in lines)
+
+
+print("//! The precomputed `BasepointTable` for `SubgroupPoint::generator`, in")
+print("//! Montgomery form. This file is generated by `doc/derive/generator.py`.")
+print()
+print("use crate::window::{NafLookupTable8, WindowTable};")
+print("use crate::{AffineNielsPoint, BasepointTable, Fq};")
+print()
+print("const fn niels(v_plus_u: [u64; 4], v_minus_u: [u64; 4], t2d: [u64; 4]) -> AffineNielsPoint {")
+print("    AffineNielsPoint {")
+print("        v_plus_u: Fq(v_plus_u),")
+print("        v_minus_u: Fq(v_minus_u),")
+print("        t2d: Fq(t2d),")
+print("    }")
+print("}")
+print()
+print("/// The full generator, of order `8r`, in affine coordinates `(u, v)`.")
+print("#[cfg(test)]")
+print("pub(crate) const FULL_GENERATOR: ([u64; 4], [u64; 4]) = (%s, %s);" % (limbs(full[0]), limbs(full[1])))
+print()
+print("/// The standard generator of the prime-order subgroup, `8` times")
+print("/// `FULL_GENERATOR`, in affine coordinates `(u, v)`.")
+print("pub(crate) const GENERATOR: ([u64; 4], [u64; 4]) = (%s, %s);" % (limbs(generator[0]), limbs(generator[1])))
+print()
+print("/// The table for `GENERATOR`.")
+print("#[rustfmt::skip]")
+print("pub(crate) static GENERATOR_TABLE: BasepointTable = BasepointTable {")
+print("    // [G, 2G, ..., 8G] * 16^(2i) for 0 <= i < 32")
+print("    windows: [")
+p = generator
+for i in range(32):
+    print("        WindowTable([")
+    for j in range(1, 9):
+        print(niels(mul(j, p), 12))
+    print("        ]),")
+    p = mul(256, p)
+print("    ],")
+print("    // [G, 3G, 5G, ..., 127G]")
+print("    odd_multiples: NafLookupTable8([")
+for k in range(64):
+    print(niels(mul(2 * k + 1, generator), 8))
+print("    ]),")
+print("};")
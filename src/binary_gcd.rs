@@ -0,0 +1,186 @@
+//! Variable-time arithmetic on canonical (non-Montgomery) four-limb integers
+//! based on the binary GCD algorithm: modular inversion and the Jacobi
+//! symbol. These are only suitable for public inputs.
+
+use crate::util::{adc, sbb};
+
+fn is_even(a: &[u64; 4]) -> bool {
+    a[0] & 1 == 0
+}
+
+fn is_one(a: &[u64; 4]) -> bool {
+    *a == [1, 0, 0, 0]
+}
+
+/// Compares `a` and `b` as little-endian integers, returning `true` if
+/// `a >= b`.
+fn geq(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    for i in (0..4).rev() {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+
+    true
+}
+
+/// Computes `a >> 1`, shifting `top` into the most significant bit.
+fn shr1(a: &mut [u64; 4], top: u64) {
+    a[0] = (a[0] >> 1) | (a[1] << 63);
+    a[1] = (a[1] >> 1) | (a[2] << 63);
+    a[2] = (a[2] >> 1) | (a[3] << 63);
+    a[3] = (a[3] >> 1) | (top << 63);
+}
+
+/// Computes `a + b`, returning the carry.
+fn add(a: &mut [u64; 4], b: &[u64; 4]) -> u64 {
+    let (d0, carry) = adc(a[0], b[0], 0);
+    let (d1, carry) = adc(a[1], b[1], carry);
+    let (d2, carry) = adc(a[2], b[2], carry);
+    let (d3, carry) = adc(a[3], b[3], carry);
+    *a = [d0, d1, d2, d3];
+
+    carry
+}
+
+/// Computes `a - b`, returning `true` if the subtraction underflowed.
+fn sub(a: &mut [u64; 4], b: &[u64; 4]) -> bool {
+    let (d0, borrow) = sbb(a[0], b[0], 0);
+    let (d1, borrow) = sbb(a[1], b[1], borrow);
+    let (d2, borrow) = sbb(a[2], b[2], borrow);
+    let (d3, borrow) = sbb(a[3], b[3], borrow);
+    *a = [d0, d1, d2, d3];
+
+    borrow != 0
+}
+
+/// Computes `x / 2 mod m` for `x` in `[0, m)` and odd `m`.
+fn halve_mod(x: &mut [u64; 4], m: &[u64; 4]) {
+    if is_even(x) {
+        shr1(x, 0);
+    } else {
+        let carry = add(x, m);
+        shr1(x, carry);
+    }
+}
+
+/// Computes `x - y mod m` for `x` and `y` in `[0, m)`.
+fn sub_mod(x: &mut [u64; 4], y: &[u64; 4], m: &[u64; 4]) {
+    if sub(x, y) {
+        add(x, m);
+    }
+}
+
+/// Computes `a^{-1} mod m` using the binary extended Euclidean algorithm,
+/// returning `None` if `a` is zero.
+///
+/// The modulus `m` must be an odd prime smaller than `2^255`, and `a` must
+/// be smaller than `m`.
+///
+/// **This operation is variable time.**
+pub fn invert_vartime(a: &[u64; 4], m: &[u64; 4]) -> Option<[u64; 4]> {
+    if *a == [0, 0, 0, 0] {
+        return None;
+    }
+
+    // We maintain the invariants x1 * a = u (mod m) and x2 * a = v (mod m)
+    // while reducing (u, v) towards their gcd, which is one.
+    let mut u = *a;
+    let mut v = *m;
+    let mut x1 = [1, 0, 0, 0];
+    let mut x2 = [0, 0, 0, 0];
+
+    while !is_one(&u) && !is_one(&v) {
+        while is_even(&u) {
+            shr1(&mut u, 0);
+            halve_mod(&mut x1, m);
+        }
+
+        while is_even(&v) {
+            shr1(&mut v, 0);
+            halve_mod(&mut x2, m);
+        }
+
+        if geq(&u, &v) {
+            sub(&mut u, &v);
+            sub_mod(&mut x1, &x2, m);
+        } else {
+            sub(&mut v, &u);
+            sub_mod(&mut x2, &x1, m);
+        }
+    }
+
+    if is_one(&u) {
+        Some(x1)
+    } else {
+        Some(x2)
+    }
+}
+
+/// Computes the Jacobi symbol `(a / n)` using the binary algorithm,
+/// returning `0`, `1` or `-1`.
+///
+/// The modulus `n` must be odd, and `a` must be smaller than `n`.
+///
+/// **This operation is variable time.**
+pub(crate) fn jacobi_vartime(a: &[u64; 4], n: &[u64; 4]) -> i8 {
+    let mut a = *a;
+    let mut n = *n;
+    let mut t = 1;
+
+    while a != [0, 0, 0, 0] {
+        // (2 / n) = -1 exactly when n = 3 or 5 (mod 8).
+        while is_even(&a) {
+            shr1(&mut a, 0);
+            if n[0] & 7 == 3 || n[0] & 7 == 5 {
+                t = -t;
+            }
+        }
+
+        // Both a and n are now odd. Ensure a >= n, using quadratic
+        // reciprocity, which flips the sign when a = n = 3 (mod 4).
+        if !geq(&a, &n) {
+            core::mem::swap(&mut a, &mut n);
+            if a[0] & 3 == 3 && n[0] & 3 == 3 {
+                t = -t;
+            }
+        }
+
+        // (a / n) = ((a - n) / n)
+        sub(&mut a, &n);
+    }
+
+    if is_one(&n) {
+        t
+    } else {
+        0
+    }
+}
+
+#[test]
+fn test_invert_vartime_small() {
+    let m = [7, 0, 0, 0];
+
+    assert_eq!(invert_vartime(&[0, 0, 0, 0], &m), None);
+    assert_eq!(invert_vartime(&[1, 0, 0, 0], &m), Some([1, 0, 0, 0]));
+    assert_eq!(invert_vartime(&[2, 0, 0, 0], &m), Some([4, 0, 0, 0]));
+    assert_eq!(invert_vartime(&[3, 0, 0, 0], &m), Some([5, 0, 0, 0]));
+    assert_eq!(invert_vartime(&[6, 0, 0, 0], &m), Some([6, 0, 0, 0]));
+}
+
+#[test]
+fn test_jacobi_vartime_small() {
+    // The squares modulo 7 are 1, 2 and 4.
+    let n = [7, 0, 0, 0];
+
+    assert_eq!(jacobi_vartime(&[0, 0, 0, 0], &n), 0);
+    assert_eq!(jacobi_vartime(&[1, 0, 0, 0], &n), 1);
+    assert_eq!(jacobi_vartime(&[2, 0, 0, 0], &n), 1);
+    assert_eq!(jacobi_vartime(&[3, 0, 0, 0], &n), -1);
+    assert_eq!(jacobi_vartime(&[4, 0, 0, 0], &n), 1);
+    assert_eq!(jacobi_vartime(&[5, 0, 0, 0], &n), -1);
+    assert_eq!(jacobi_vartime(&[6, 0, 0, 0], &n), -1);
+
+    // (3 / 9) = 0 for a composite modulus sharing a factor.
+    assert_eq!(jacobi_vartime(&[3, 0, 0, 0], &[9, 0, 0, 0]), 0);
+}
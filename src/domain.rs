@@ -0,0 +1,338 @@
+//! Radix-2 evaluation domains over prime fields with large 2-adicity, and
+//! the number-theoretic transforms between coefficient and evaluation form.
+//!
+//! `Fq` has 2-adicity 32, so it supports domains of every size up to `2^32`.
+//! `Fr` has 2-adicity 1, so it only supports domains of size 1 and 2.
+
+use crate::field::{Field, PrimeField};
+
+/// The multiplicative subgroup of order `2^k` of a prime field, generated
+/// by a primitive `2^k`-th root of unity `omega`, along with the constants
+/// needed to transform vectors of length `2^k` into and out of it.
+#[derive(Clone, Copy, Debug)]
+pub struct EvaluationDomain<F: PrimeField> {
+    log_size: u32,
+    omega: F,
+    omega_inv: F,
+    size_inv: F,
+    coset_shift: F,
+    coset_shift_inv: F,
+}
+
+impl<F: PrimeField> EvaluationDomain<F> {
+    /// Constructs the domain of size `2^log_size`, or returns `None` if
+    /// the field has no such subgroup (if `log_size` exceeds `F::S`).
+    pub fn new(log_size: u32) -> Option<Self> {
+        if log_size > F::S {
+            return None;
+        }
+
+        // ROOT_OF_UNITY has order 2^S, so squaring it S - k times gives
+        // an element of order 2^k.
+        let mut omega = F::ROOT_OF_UNITY;
+        for _ in log_size..F::S {
+            omega = omega.square();
+        }
+
+        Some(EvaluationDomain {
+            log_size,
+            omega,
+            omega_inv: omega.invert_nonzero(),
+            size_inv: F::from(2)
+                .pow_vartime(&[u64::from(log_size), 0, 0, 0])
+                .invert_nonzero(),
+            coset_shift: F::MULTIPLICATIVE_GENERATOR,
+            coset_shift_inv: F::MULTIPLICATIVE_GENERATOR.invert_nonzero(),
+        })
+    }
+
+    /// Constructs the smallest domain with at least `min_size` elements,
+    /// or returns `None` if the field does not have one.
+    pub fn with_min_size(min_size: u64) -> Option<Self> {
+        let mut log_size = 0;
+        while (1u64 << log_size) < min_size {
+            log_size += 1;
+            if log_size > F::S {
+                return None;
+            }
+        }
+
+        Self::new(log_size)
+    }
+
+    /// Returns `log2` of the size of this domain.
+    pub fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// Returns the number of elements in this domain.
+    pub fn size(&self) -> u64 {
+        1 << self.log_size
+    }
+
+    /// Returns the generator `omega` of this domain, which is a primitive
+    /// root of unity of order `self.size()`.
+    pub fn generator(&self) -> F {
+        self.omega
+    }
+
+    /// Returns the inverse of the generator of this domain.
+    pub fn generator_inv(&self) -> F {
+        self.omega_inv
+    }
+
+    /// Returns the element `omega^i` of this domain.
+    pub fn element(&self, i: u64) -> F {
+        self.omega.pow_vartime(&[i, 0, 0, 0])
+    }
+
+    /// Returns the multiplicative generator `g` of the field, which is used
+    /// to shift the domain onto the coset `g * <omega>` in `coset_ntt` and
+    /// `coset_intt`.
+    pub fn coset_shift(&self) -> F {
+        self.coset_shift
+    }
+
+    /// Evaluates the vanishing polynomial of this domain, `X^n - 1`, at
+    /// `tau`.
+    pub fn evaluate_vanishing_polynomial(&self, tau: &F) -> F {
+        let mut tmp = *tau;
+        for _ in 0..self.log_size {
+            tmp = tmp.square();
+        }
+
+        tmp - F::one()
+    }
+
+    /// Transforms the coefficients `a_0, ..., a_{n-1}` of a polynomial `a`
+    /// in place into its evaluations `a(omega^0), ..., a(omega^{n-1})`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values.len()` is not the size of this domain.
+    pub fn ntt(&self, values: &mut [F]) {
+        self.assert_size(values);
+        radix2_ntt(values, self.omega, self.log_size);
+    }
+
+    /// Transforms the evaluations `a(omega^0), ..., a(omega^{n-1})` of a
+    /// polynomial `a` of degree less than `n` in place back into its
+    /// coefficients. This is the inverse of `ntt`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values.len()` is not the size of this domain.
+    pub fn intt(&self, values: &mut [F]) {
+        self.assert_size(values);
+        radix2_ntt(values, self.omega_inv, self.log_size);
+
+        for value in values.iter_mut() {
+            *value *= &self.size_inv;
+        }
+    }
+
+    /// Transforms the coefficients of a polynomial `a` in place into its
+    /// evaluations `a(g * omega^0), ..., a(g * omega^{n-1})` over the coset
+    /// of this domain shifted by `g = self.coset_shift()`. Since the coset
+    /// is disjoint from the domain, this is useful for dividing by the
+    /// vanishing polynomial.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values.len()` is not the size of this domain.
+    pub fn coset_ntt(&self, values: &mut [F]) {
+        self.assert_size(values);
+        distribute_powers(values, self.coset_shift);
+        radix2_ntt(values, self.omega, self.log_size);
+    }
+
+    /// Transforms the evaluations of a polynomial over the coset shifted by
+    /// `self.coset_shift()` in place back into its coefficients. This is
+    /// the inverse of `coset_ntt`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values.len()` is not the size of this domain.
+    pub fn coset_intt(&self, values: &mut [F]) {
+        self.intt(values);
+        distribute_powers(values, self.coset_shift_inv);
+    }
+
+    fn assert_size(&self, values: &[F]) {
+        assert_eq!(
+            values.len() as u64,
+            self.size(),
+            "input length must match the domain size"
+        );
+    }
+}
+
+/// Multiplies `values[i]` by `g^i` for every `i`.
+fn distribute_powers<F: Field>(values: &mut [F], g: F) {
+    let mut power = F::one();
+    for value in values.iter_mut() {
+        *value *= &power;
+        power *= &g;
+    }
+}
+
+/// Evaluates the polynomial with coefficients `values` at the powers of
+/// `omega`, an element of order `2^log_size`, in place. This is the
+/// iterative Cooley-Tukey algorithm: a bit-reversal permutation followed
+/// by `log_size` rounds of butterflies.
+fn radix2_ntt<F: Field>(values: &mut [F], omega: F, log_size: u32) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+
+    for k in 0..n {
+        let rk = bitreverse(k, log_size);
+        if k < rk {
+            values.swap(rk, k);
+        }
+    }
+
+    // Each round combines pairs of transforms of size `half` into
+    // transforms of size `2 * half`, using the root of unity of order
+    // `2 * half`, which is omega^(n / (2 * half)).
+    let mut half = 1;
+    for round in 0..log_size {
+        let mut w_m = omega;
+        for _ in (round + 1)..log_size {
+            w_m = w_m.square();
+        }
+
+        let mut w = F::one();
+        for j in 0..half {
+            let mut k = j;
+            while k < n {
+                let t = values[k + half] * &w;
+                values[k + half] = values[k] - &t;
+                values[k] += &t;
+                k += 2 * half;
+            }
+            w *= &w_m;
+        }
+
+        half *= 2;
+    }
+}
+
+fn bitreverse(mut n: usize, l: u32) -> usize {
+    let mut r = 0;
+    for _ in 0..l {
+        r = (r << 1) | (n & 1);
+        n >>= 1;
+    }
+    r
+}
+
+#[cfg(test)]
+use crate::{Fq, Fr};
+
+#[cfg(test)]
+fn naive_evaluate<F: Field>(coeffs: &[F], point: F) -> F {
+    let mut result = F::zero();
+    for coeff in coeffs.iter().rev() {
+        result = result * &point + coeff;
+    }
+    result
+}
+
+#[test]
+fn test_domain_sizes() {
+    assert!(EvaluationDomain::<Fq>::new(32).is_some());
+    assert!(EvaluationDomain::<Fq>::new(33).is_none());
+    assert!(EvaluationDomain::<Fr>::new(1).is_some());
+    assert!(EvaluationDomain::<Fr>::new(2).is_none());
+
+    assert_eq!(EvaluationDomain::<Fq>::with_min_size(0).unwrap().size(), 1);
+    assert_eq!(EvaluationDomain::<Fq>::with_min_size(5).unwrap().size(), 8);
+    assert_eq!(EvaluationDomain::<Fq>::with_min_size(8).unwrap().size(), 8);
+    assert_eq!(
+        EvaluationDomain::<Fq>::with_min_size(1 << 32)
+            .unwrap()
+            .log_size(),
+        32
+    );
+    assert!(EvaluationDomain::<Fq>::with_min_size((1 << 32) + 1).is_none());
+    assert!(EvaluationDomain::<Fr>::with_min_size(3).is_none());
+}
+
+#[test]
+fn test_generator_order() {
+    for log_size in 0..=32 {
+        let domain = EvaluationDomain::<Fq>::new(log_size).unwrap();
+        let omega = domain.generator();
+        assert_eq!(omega * domain.generator_inv(), Fq::one());
+
+        // omega^(n / 2) = -1 for a primitive n-th root of unity.
+        let mut tmp = omega;
+        for _ in 1..log_size {
+            tmp = tmp.square();
+        }
+        if log_size > 0 {
+            assert_eq!(tmp, -Fq::one());
+        }
+        assert_eq!(domain.evaluate_vanishing_polynomial(&omega), Fq::zero());
+    }
+}
+
+#[test]
+fn test_ntt_matches_naive_evaluation() {
+    for log_size in 0..6 {
+        let domain = EvaluationDomain::<Fq>::new(log_size).unwrap();
+        let n = domain.size() as usize;
+
+        let mut coeffs = [Fq::zero(); 32];
+        for (i, c) in coeffs.iter_mut().enumerate().take(n) {
+            *c = Fq::from(i as u64 * 7 + 3).square();
+        }
+        let coeffs = &coeffs[..n];
+
+        let mut values = [Fq::zero(); 32];
+        values[..n].copy_from_slice(coeffs);
+        domain.ntt(&mut values[..n]);
+        for (i, value) in values[..n].iter().enumerate() {
+            assert_eq!(*value, naive_evaluate(coeffs, domain.element(i as u64)));
+        }
+
+        domain.intt(&mut values[..n]);
+        assert_eq!(&values[..n], coeffs);
+
+        values[..n].copy_from_slice(coeffs);
+        domain.coset_ntt(&mut values[..n]);
+        for (i, value) in values[..n].iter().enumerate() {
+            let point = domain.coset_shift() * domain.element(i as u64);
+            assert_eq!(*value, naive_evaluate(coeffs, point));
+        }
+
+        domain.coset_intt(&mut values[..n]);
+        assert_eq!(&values[..n], coeffs);
+    }
+}
+
+#[test]
+fn test_fr_ntt() {
+    let domain = EvaluationDomain::<Fr>::new(1).unwrap();
+    let mut values = [Fr::from(3), Fr::from(5)];
+    domain.ntt(&mut values);
+    assert_eq!(values, [Fr::from(8), -Fr::from(2)]);
+    domain.intt(&mut values);
+    assert_eq!(values, [Fr::from(3), Fr::from(5)]);
+}
+
+#[test]
+#[should_panic]
+fn test_ntt_wrong_size() {
+    let domain = EvaluationDomain::<Fq>::new(2).unwrap();
+    domain.ntt(&mut [Fq::one(); 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_coset_ntt_wrong_size() {
+    let domain = EvaluationDomain::<Fq>::new(2).unwrap();
+    domain.coset_ntt(&mut [Fq::one(); 3]);
+}
@@ -0,0 +1,321 @@
+//! Traits shared by the field types in this crate, so that code can be
+//! written generically over `Fq` and `Fr`.
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use crate::{Fq, Fr};
+
+/// This trait represents an element of a field.
+pub trait Field:
+    Sized
+    + Copy
+    + Default
+    + fmt::Debug
+    + Eq
+    + ConditionallySelectable
+    + ConstantTimeEq
+    + Neg<Output = Self>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + for<'a> Add<&'a Self, Output = Self>
+    + for<'a> Sub<&'a Self, Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + for<'a> AddAssign<&'a Self>
+    + for<'a> SubAssign<&'a Self>
+    + for<'a> MulAssign<&'a Self>
+{
+    /// Returns the additive identity.
+    fn zero() -> Self;
+
+    /// Returns the multiplicative identity.
+    fn one() -> Self;
+
+    /// Returns true iff this element is zero.
+    fn is_zero(&self) -> Choice {
+        self.ct_eq(&Self::zero())
+    }
+
+    /// Doubles this element.
+    fn double(&self) -> Self;
+
+    /// Squares this element.
+    fn square(&self) -> Self;
+
+    /// Exponentiates `self` by `by`, where `by` is a
+    /// little-endian order integer exponent.
+    fn pow(&self, by: &[u64; 4]) -> Self;
+
+    /// Exponentiates `self` by `by`, where `by` is a
+    /// little-endian order integer exponent.
+    ///
+    /// **This operation is variable time with respect
+    /// to the exponent.**
+    fn pow_vartime(&self, by: &[u64; 4]) -> Self;
+
+    /// Computes the multiplicative inverse of this element,
+    /// failing if the element is zero.
+    fn invert(&self) -> CtOption<Self>;
+
+    /// Computes the multiplicative inverse of this element,
+    /// returning zero if the element is zero.
+    fn invert_nonzero(&self) -> Self;
+
+    /// Computes the multiplicative inverse of this element,
+    /// failing if the element is zero.
+    ///
+    /// **This operation is variable time.**
+    fn invert_vartime(&self) -> Option<Self>;
+
+    /// Computes the square root of this element, if it exists.
+    fn sqrt(&self) -> CtOption<Self>;
+
+    /// Computes the square root of this element, if it exists.
+    ///
+    /// **This operation is variable time.**
+    fn sqrt_vartime(&self) -> Option<Self>;
+
+    /// Inverts every element of `values` in place with a single
+    /// inversion, using `scratch` (which must be at least as long as
+    /// `values`) for intermediate products. Zero elements are left
+    /// unchanged, and the returned `Choice` is true iff none were zero.
+    fn batch_invert_with_scratch(values: &mut [Self], scratch: &mut [Self]) -> Choice;
+
+    /// Computes `sum_i a[i] * b[i]`, panicking if the slices have
+    /// different lengths.
+    fn sum_of_products(a: &[Self], b: &[Self]) -> Self;
+}
+
+/// This trait represents an element of a prime field of at most
+/// 256 bits, with a canonical little-endian byte encoding.
+pub trait PrimeField: Field + From<u64> {
+    /// The number of bits needed to represent the modulus.
+    const NUM_BITS: u32;
+
+    /// The number of bits of data that can be reliably stored in a
+    /// field element, which is `NUM_BITS - 1`.
+    const CAPACITY: u32;
+
+    /// The 2-adicity of the field: the largest `s` such that `2^s`
+    /// divides `p - 1`.
+    const S: u32;
+
+    /// The little-endian byte encoding of the modulus.
+    const MODULUS: [u8; 32];
+
+    /// A fixed multiplicative generator of the field, which is in
+    /// particular a quadratic non-residue.
+    const MULTIPLICATIVE_GENERATOR: Self;
+
+    /// A primitive `2^S`-th root of unity, computed as
+    /// `MULTIPLICATIVE_GENERATOR^t` where `p - 1 = t * 2^S` with `t` odd.
+    const ROOT_OF_UNITY: Self;
+
+    /// Attempts to convert a little-endian byte representation of
+    /// a field element, failing if the input is not canonical.
+    fn from_bytes(bytes: [u8; 32]) -> CtOption<Self>;
+
+    /// Attempts to convert a little-endian byte representation of
+    /// a field element, failing if the input is not canonical.
+    ///
+    /// **This operation is variable time.**
+    fn from_bytes_vartime(bytes: [u8; 32]) -> Option<Self>;
+
+    /// Converts an element into a byte representation in
+    /// little-endian byte order.
+    fn to_bytes(&self) -> [u8; 32];
+
+    /// Converts a 512-bit little-endian integer into an element
+    /// by reducing it modulo the field modulus.
+    fn from_bytes_wide(bytes: [u8; 64]) -> Self;
+}
+
+macro_rules! impl_field {
+    ($field:ident) => {
+        impl Field for $field {
+            fn zero() -> Self {
+                $field::zero()
+            }
+
+            fn one() -> Self {
+                $field::one()
+            }
+
+            fn double(&self) -> Self {
+                $field::double(self)
+            }
+
+            fn square(&self) -> Self {
+                $field::square(self)
+            }
+
+            fn pow(&self, by: &[u64; 4]) -> Self {
+                $field::pow(self, by)
+            }
+
+            fn pow_vartime(&self, by: &[u64; 4]) -> Self {
+                $field::pow_vartime(self, by)
+            }
+
+            fn invert(&self) -> CtOption<Self> {
+                $field::invert(self)
+            }
+
+            fn invert_nonzero(&self) -> Self {
+                $field::invert_nonzero(self)
+            }
+
+            fn invert_vartime(&self) -> Option<Self> {
+                $field::invert_vartime(self)
+            }
+
+            fn sqrt(&self) -> CtOption<Self> {
+                $field::sqrt(self)
+            }
+
+            fn sqrt_vartime(&self) -> Option<Self> {
+                $field::sqrt_vartime(self)
+            }
+
+            fn batch_invert_with_scratch(values: &mut [Self], scratch: &mut [Self]) -> Choice {
+                $field::batch_invert_with_scratch(values, scratch)
+            }
+
+            fn sum_of_products(a: &[Self], b: &[Self]) -> Self {
+                $field::sum_of_products(a, b)
+            }
+        }
+    };
+}
+
+impl_field!(Fq);
+impl_field!(Fr);
+
+impl PrimeField for Fq {
+    const NUM_BITS: u32 = 255;
+    const CAPACITY: u32 = 254;
+    const S: u32 = crate::fq::S;
+    const MODULUS: [u8; 32] = [
+        0x01, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xfe, 0x5b, 0xfe, 0xff, 0x02, 0xa4, 0xbd,
+        0x53, 0x05, 0xd8, 0xa1, 0x09, 0x08, 0xd8, 0x39, 0x33, 0x48, 0x7d, 0x9d, 0x29, 0x53, 0xa7,
+        0xed, 0x73,
+    ];
+    const MULTIPLICATIVE_GENERATOR: Self = crate::fq::GENERATOR;
+    const ROOT_OF_UNITY: Self = crate::fq::ROOT_OF_UNITY;
+
+    fn from_bytes(bytes: [u8; 32]) -> CtOption<Self> {
+        Fq::from_bytes(bytes)
+    }
+
+    fn from_bytes_vartime(bytes: [u8; 32]) -> Option<Self> {
+        Fq::from_bytes_vartime(bytes)
+    }
+
+    fn to_bytes(&self) -> [u8; 32] {
+        Fq::into_bytes(self)
+    }
+
+    fn from_bytes_wide(bytes: [u8; 64]) -> Self {
+        Fq::from_bytes_wide(bytes)
+    }
+}
+
+impl PrimeField for Fr {
+    const NUM_BITS: u32 = 252;
+    const CAPACITY: u32 = 251;
+    const S: u32 = crate::fr::S;
+    const MODULUS: [u8; 32] = [
+        0xb7, 0x2c, 0xf7, 0xd6, 0x5e, 0x0e, 0x97, 0xd0, 0x82, 0x10, 0xc8, 0xcc, 0x93, 0x20, 0x68,
+        0xa6, 0x00, 0x3b, 0x34, 0x01, 0x01, 0x3b, 0x67, 0x06, 0xa9, 0xaf, 0x33, 0x65, 0xea, 0xb4,
+        0x7d, 0x0e,
+    ];
+    const MULTIPLICATIVE_GENERATOR: Self = crate::fr::GENERATOR;
+    const ROOT_OF_UNITY: Self = crate::fr::ROOT_OF_UNITY;
+
+    fn from_bytes(bytes: [u8; 32]) -> CtOption<Self> {
+        Fr::from_bytes(bytes)
+    }
+
+    fn from_bytes_vartime(bytes: [u8; 32]) -> Option<Self> {
+        Fr::from_bytes_vartime(bytes)
+    }
+
+    fn to_bytes(&self) -> [u8; 32] {
+        Fr::into_bytes(self)
+    }
+
+    fn from_bytes_wide(bytes: [u8; 64]) -> Self {
+        Fr::from_bytes_wide(bytes)
+    }
+}
+
+#[cfg(test)]
+fn check_constants<F: PrimeField>() {
+    assert_eq!(F::CAPACITY, F::NUM_BITS - 1);
+
+    // The generator is a quadratic non-residue.
+    assert!(F::MULTIPLICATIVE_GENERATOR.sqrt_vartime().is_none());
+
+    // ROOT_OF_UNITY has order exactly 2^S.
+    let mut root = F::ROOT_OF_UNITY;
+    for _ in 0..(F::S - 1) {
+        root = root.square();
+    }
+    assert!(root != F::one());
+    assert_eq!(root.square(), F::one());
+
+    // The modulus is not canonical, but the modulus minus one is.
+    assert!(bool::from(F::from_bytes(F::MODULUS).is_none()));
+    let mut minus_one = F::MODULUS;
+    minus_one[0] -= 1;
+    assert_eq!(F::from_bytes(minus_one).unwrap(), -F::one());
+    assert_eq!(F::to_bytes(&-F::one()), minus_one);
+
+    // ROOT_OF_UNITY is MULTIPLICATIVE_GENERATOR^t, where p - 1 = t * 2^S.
+    let mut t = [0u64; 4];
+    for (limb, bytes) in t.iter_mut().zip(minus_one.chunks(8)) {
+        for (j, byte) in bytes.iter().enumerate() {
+            *limb |= u64::from(*byte) << (8 * j);
+        }
+    }
+    for _ in 0..F::S {
+        for i in 0..4 {
+            t[i] >>= 1;
+            if i < 3 {
+                t[i] |= t[i + 1] << 63;
+            }
+        }
+    }
+    assert_eq!(t[0] & 1, 1);
+    assert_eq!(F::MULTIPLICATIVE_GENERATOR.pow_vartime(&t), F::ROOT_OF_UNITY);
+}
+
+#[test]
+fn test_fq_constants() {
+    check_constants::<Fq>();
+}
+
+#[test]
+fn test_fr_constants() {
+    check_constants::<Fr>();
+}
+
+#[test]
+fn test_generic_field_ops() {
+    fn square_via_trait<F: Field>(x: F) -> F {
+        let mut y = x;
+        y *= &x;
+        y
+    }
+
+    let a = Fq::from(12345);
+    assert_eq!(square_via_trait(a), a.square());
+    assert!(bool::from(Field::is_zero(&Fq::zero())));
+    assert!(!bool::from(Field::is_zero(&Fr::one())));
+}
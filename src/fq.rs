@@ -1,9 +1,8 @@
-use core::fmt;
-use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+#[cfg(test)]
+use core::ops::{AddAssign, MulAssign};
 
-use byteorder::{ByteOrder, LittleEndian};
-use crate::util::{adc, mac, sbb};
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use crate::binary_gcd;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 /// Represents an element of `GF(q)`.
 // The internal representation of this type is four 64-bit unsigned
@@ -12,49 +11,6 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 #[derive(Clone, Copy, Eq)]
 pub struct Fq(pub(crate) [u64; 4]);
 
-impl fmt::Debug for Fq {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let tmp = self.into_bytes();
-        write!(f, "0x")?;
-        for &b in tmp.iter().rev() {
-            write!(f, "{:02x}", b)?;
-        }
-        Ok(())
-    }
-}
-
-impl From<u64> for Fq {
-    fn from(val: u64) -> Fq {
-        Fq([val, 0, 0, 0]) * R2
-    }
-}
-
-impl ConstantTimeEq for Fq {
-    fn ct_eq(&self, other: &Self) -> Choice {
-        self.0[0].ct_eq(&other.0[0])
-            & self.0[1].ct_eq(&other.0[1])
-            & self.0[2].ct_eq(&other.0[2])
-            & self.0[3].ct_eq(&other.0[3])
-    }
-}
-
-impl PartialEq for Fq {
-    fn eq(&self, other: &Self) -> bool {
-        self.ct_eq(other).unwrap_u8() == 1
-    }
-}
-
-impl ConditionallySelectable for Fq {
-    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
-        Fq([
-            u64::conditional_select(&a.0[0], &b.0[0], choice),
-            u64::conditional_select(&a.0[1], &b.0[1], choice),
-            u64::conditional_select(&a.0[2], &b.0[2], choice),
-            u64::conditional_select(&a.0[3], &b.0[3], choice),
-        ])
-    }
-}
-
 // Constant representing the modulus
 // q = 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001
 const MODULUS: Fq = Fq([
@@ -64,107 +20,6 @@ const MODULUS: Fq = Fq([
     0x73eda753299d7d48,
 ]);
 
-impl<'a> Neg for &'a Fq {
-    type Output = Fq;
-
-    #[inline]
-    fn neg(self) -> Fq {
-        // Subtract `self` from `MODULUS` to negate. Ignore the final
-        // borrow because it cannot underflow; self is guaranteed to
-        // be in the field.
-        let (d0, borrow) = sbb(MODULUS.0[0], self.0[0], 0);
-        let (d1, borrow) = sbb(MODULUS.0[1], self.0[1], borrow);
-        let (d2, borrow) = sbb(MODULUS.0[2], self.0[2], borrow);
-        let (d3, _) = sbb(MODULUS.0[3], self.0[3], borrow);
-
-        // `tmp` could be `MODULUS` if `self` was zero. Create a mask that is
-        // zero if `self` was zero, and `u64::max_value()` if self was nonzero.
-        let mask = u64::from((self.0[0] | self.0[1] | self.0[2] | self.0[3]) == 0).wrapping_sub(1);
-
-        Fq([d0 & mask, d1 & mask, d2 & mask, d3 & mask])
-    }
-}
-
-impl Neg for Fq {
-    type Output = Fq;
-
-    #[inline]
-    fn neg(self) -> Fq {
-        -&self
-    }
-}
-
-impl<'a, 'b> Sub<&'b Fq> for &'a Fq {
-    type Output = Fq;
-
-    #[inline]
-    fn sub(self, rhs: &'b Fq) -> Fq {
-        let (d0, borrow) = sbb(self.0[0], rhs.0[0], 0);
-        let (d1, borrow) = sbb(self.0[1], rhs.0[1], borrow);
-        let (d2, borrow) = sbb(self.0[2], rhs.0[2], borrow);
-        let (d3, borrow) = sbb(self.0[3], rhs.0[3], borrow);
-
-        // If underflow occurred on the final limb, borrow = 0xfff...fff, otherwise
-        // borrow = 0x000...000. Thus, we use it as a mask to conditionally add the modulus.
-        let (d0, carry) = adc(d0, MODULUS.0[0] & borrow, 0);
-        let (d1, carry) = adc(d1, MODULUS.0[1] & borrow, carry);
-        let (d2, carry) = adc(d2, MODULUS.0[2] & borrow, carry);
-        let (d3, _) = adc(d3, MODULUS.0[3] & borrow, carry);
-
-        Fq([d0, d1, d2, d3])
-    }
-}
-
-impl<'a, 'b> Add<&'b Fq> for &'a Fq {
-    type Output = Fq;
-
-    #[inline]
-    fn add(self, rhs: &'b Fq) -> Fq {
-        let (d0, carry) = adc(self.0[0], rhs.0[0], 0);
-        let (d1, carry) = adc(self.0[1], rhs.0[1], carry);
-        let (d2, carry) = adc(self.0[2], rhs.0[2], carry);
-        let (d3, _) = adc(self.0[3], rhs.0[3], carry);
-
-        // Attempt to subtract the modulus, to ensure the value
-        // is smaller than the modulus.
-        Fq([d0, d1, d2, d3]) - &MODULUS
-    }
-}
-
-impl<'a, 'b> Mul<&'b Fq> for &'a Fq {
-    type Output = Fq;
-
-    #[inline]
-    fn mul(self, rhs: &'b Fq) -> Fq {
-        // Schoolbook multiplication
-
-        let (r0, carry) = mac(0, self.0[0], rhs.0[0], 0);
-        let (r1, carry) = mac(0, self.0[0], rhs.0[1], carry);
-        let (r2, carry) = mac(0, self.0[0], rhs.0[2], carry);
-        let (r3, r4) = mac(0, self.0[0], rhs.0[3], carry);
-
-        let (r1, carry) = mac(r1, self.0[1], rhs.0[0], 0);
-        let (r2, carry) = mac(r2, self.0[1], rhs.0[1], carry);
-        let (r3, carry) = mac(r3, self.0[1], rhs.0[2], carry);
-        let (r4, r5) = mac(r4, self.0[1], rhs.0[3], carry);
-
-        let (r2, carry) = mac(r2, self.0[2], rhs.0[0], 0);
-        let (r3, carry) = mac(r3, self.0[2], rhs.0[1], carry);
-        let (r4, carry) = mac(r4, self.0[2], rhs.0[2], carry);
-        let (r5, r6) = mac(r5, self.0[2], rhs.0[3], carry);
-
-        let (r3, carry) = mac(r3, self.0[3], rhs.0[0], 0);
-        let (r4, carry) = mac(r4, self.0[3], rhs.0[1], carry);
-        let (r5, carry) = mac(r5, self.0[3], rhs.0[2], carry);
-        let (r6, r7) = mac(r6, self.0[3], rhs.0[3], carry);
-
-        Fq::montgomery_reduce(r0, r1, r2, r3, r4, r5, r6, r7)
-    }
-}
-
-impl_binops_additive!(Fq, Fq);
-impl_binops_multiplicative!(Fq, Fq);
-
 /// INV = -(q^{-1} mod 2^64) mod 2^64
 const INV: u64 = 0xfffffffeffffffff;
 
@@ -192,178 +47,213 @@ const R3: Fq = Fq([
     0x6e2a5bb9c8db33e9,
 ]);
 
-// /// 7*R mod q
-// const GENERATOR: Fq = Fq([
-//     0x0000000efffffff1,
-//     0x17e363d300189c0f,
-//     0xff9c57876f8457b0,
-//     0x351332208fc5a8c4,
-// ]);
+/// 7*R mod q
+pub(crate) const GENERATOR: Fq = Fq([
+    0x0000000efffffff1,
+    0x17e363d300189c0f,
+    0xff9c57876f8457b0,
+    0x351332208fc5a8c4,
+]);
 
-const S: u32 = 32;
+pub(crate) const S: u32 = 32;
 
 /// GENERATOR^t where t * 2^s + 1 = q
 /// with t odd. In other words, this
 /// is a 2^s root of unity.
-const ROOT_OF_UNITY: Fq = Fq([
+pub(crate) const ROOT_OF_UNITY: Fq = Fq([
     0xb9b58d8c5f0e466a,
     0x5b1b4c801819d7ec,
     0x0af53ae352a31e64,
     0x5bf3adda19e9b27b,
 ]);
 
-impl Default for Fq {
-    fn default() -> Self {
-        Self::zero()
-    }
-}
+/// GENERATOR^((t + 1) // 2) where t * 2^s + 1 = q
+/// with t odd.
+const GENERATOR_POW_T_PLUS_1_OVER_2: Fq = Fq([
+    0xa854756fef16fa81,
+    0x0c90069f14b7e522,
+    0x906a88c01e88c9ef,
+    0x1dc3e56450c37f27,
+]);
+
+impl_montgomery_field!(Fq, MODULUS, INV, R, R2, R3);
 
 impl Fq {
-    pub fn zero() -> Fq {
-        Fq([0, 0, 0, 0])
+    /// Computes the Legendre symbol via Euler's criterion,
+    /// returning zero, one or minus one as an element of `Fq`.
+    fn euler_criterion(&self) -> Self {
+        // self^((q - 1) // 2)
+        self.pow_vartime(&[
+            0x7fffffff80000000,
+            0xa9ded2017fff2dff,
+            0x199cec0404d0ec02,
+            0x39f6d3a994cebea4,
+        ])
     }
 
-    pub fn one() -> Fq {
-        R
-    }
+    /// Computes the Legendre symbol of this element, which is
+    /// `0` if the element is zero, `1` if it is a nonzero square
+    /// and `-1` otherwise.
+    pub fn legendre(&self) -> i8 {
+        let symbol = self.euler_criterion();
 
-    #[inline]
-    pub fn double(&self) -> Fq {
-        self + self
+        let mut result = -1i8;
+        result.conditional_assign(&1, symbol.ct_eq(&Fq::one()));
+        result.conditional_assign(&0, symbol.ct_eq(&Fq::zero()));
+
+        result
     }
 
-    /// Attempts to convert a little-endian byte representation of
-    /// a field element into an element of `Fq`, failing if the input
-    /// is not canonical (is not smaller than q).
+    /// Computes the Legendre symbol of this element, which is
+    /// `0` if the element is zero, `1` if it is a nonzero square
+    /// and `-1` otherwise. This uses the binary Jacobi symbol
+    /// algorithm rather than an exponentiation.
     ///
     /// **This operation is variable time.**
-    pub fn from_bytes_vartime(bytes: [u8; 32]) -> Option<Fq> {
-        let mut tmp = Fq([0, 0, 0, 0]);
-
-        tmp.0[0] = LittleEndian::read_u64(&bytes[0..8]);
-        tmp.0[1] = LittleEndian::read_u64(&bytes[8..16]);
-        tmp.0[2] = LittleEndian::read_u64(&bytes[16..24]);
-        tmp.0[3] = LittleEndian::read_u64(&bytes[24..32]);
-
-        // Check if the value is in the field
-        for i in (0..4).rev() {
-            if tmp.0[i] < MODULUS.0[i] {
-                // Convert to Montgomery form by computing
-                // (a.R^{-1} * R^2) / R = a.R
-                tmp.mul_assign(&R2);
-
-                return Some(tmp);
-            }
+    pub fn legendre_vartime(&self) -> i8 {
+        // Convert out of Montgomery form by computing (a.R) / R = a.
+        // Since q is prime, the Jacobi symbol is the Legendre symbol.
+        let tmp = Fq::montgomery_reduce(self.0[0], self.0[1], self.0[2], self.0[3], 0, 0, 0, 0);
 
-            if tmp.0[i] > MODULUS.0[i] {
-                return None;
-            }
-        }
+        binary_gcd::jacobi_vartime(&tmp.0, &MODULUS.0)
+    }
 
-        // Value is equal to the modulus
-        None
+    /// Returns whether this element is a square (including zero),
+    /// without computing a square root.
+    pub fn is_square(&self) -> Choice {
+        let symbol = self.euler_criterion();
+
+        symbol.ct_eq(&Fq::one()) | symbol.ct_eq(&Fq::zero())
     }
 
-    /// Converts an element of `Fq` into a byte representation in
-    /// little-endian byte order.
-    pub fn into_bytes(&self) -> [u8; 32] {
-        // Turn into canonical form by computing
-        // (a.R) / R = a
-        let tmp = Fq::montgomery_reduce(self.0[0], self.0[1], self.0[2], self.0[3], 0, 0, 0, 0);
+    /// Computes the square root of this element, if it exists.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        // Tonelli-Shank's algorithm for q mod 16 = 1
+        // https://eprint.iacr.org/2012/685.pdf (page 12, algorithm 5)
+        //
+        // Every loop below runs for a fixed number of iterations and
+        // all data-dependent decisions are made with conditional
+        // selection, so this performs the same work for every input.
+
+        // w = self^((t - 1) // 2)
+        let w = self.pow_vartime(&[
+            0x7fff2dff7fffffff,
+            0x04d0ec02a9ded201,
+            0x94cebea4199cec04,
+            0x0000000039f6d3a9,
+        ]);
+
+        let mut v = S;
+        let mut x = self * &w;
+        let mut b = x * &w;
+
+        // Initialize z as the 2^s root of unity
+        let mut z = ROOT_OF_UNITY;
+
+        for max_v in (1..=S).rev() {
+            let mut k = 1;
+            let mut tmp = b.square();
+            let mut j_less_than_v: Choice = 1.into();
+
+            for j in 2..max_v {
+                let tmp_is_one = tmp.ct_eq(&Fq::one());
+                let squared = Fq::conditional_select(&tmp, &z, tmp_is_one).square();
+                tmp = Fq::conditional_select(&squared, &tmp, tmp_is_one);
+                let new_z = Fq::conditional_select(&z, &squared, tmp_is_one);
+                j_less_than_v &= !j.ct_eq(&v);
+                k = u32::conditional_select(&j, &k, tmp_is_one);
+                z = Fq::conditional_select(&z, &new_z, j_less_than_v);
+            }
 
-        let mut res = [0; 32];
-        LittleEndian::write_u64(&mut res[0..8], tmp.0[0]);
-        LittleEndian::write_u64(&mut res[8..16], tmp.0[1]);
-        LittleEndian::write_u64(&mut res[16..24], tmp.0[2]);
-        LittleEndian::write_u64(&mut res[24..32], tmp.0[3]);
+            let result = x * &z;
+            x = Fq::conditional_select(&result, &x, b.ct_eq(&Fq::one()));
+            z = z.square();
+            b *= &z;
+            v = k;
+        }
 
-        res
+        // Only return Some if it's the square root.
+        CtOption::new(x, x.square().ct_eq(self))
     }
 
-    pub fn from_bytes_wide(bytes: [u8; 64]) -> Fq {
-        Fq::from_u512([
-            LittleEndian::read_u64(&bytes[0..8]),
-            LittleEndian::read_u64(&bytes[8..16]),
-            LittleEndian::read_u64(&bytes[16..24]),
-            LittleEndian::read_u64(&bytes[24..32]),
-            LittleEndian::read_u64(&bytes[32..40]),
-            LittleEndian::read_u64(&bytes[40..48]),
-            LittleEndian::read_u64(&bytes[48..56]),
-            LittleEndian::read_u64(&bytes[56..64]),
-        ])
-    }
+    /// Computes the square root of the ratio `u / v` using a single
+    /// exponentiation, rather than an inversion followed by a square root.
+    ///
+    /// Returns `(1, sqrt(u / v))` if `v` is nonzero and `u / v` is a square
+    /// (which includes the case `u = 0`). If `v` is nonzero and `u / v` is
+    /// not a square, returns `(0, sqrt(g * u / v))` where `g` is the
+    /// multiplicative generator `7`. If `v` is zero, returns `(0, 0)`.
+    pub fn sqrt_ratio(u: &Fq, v: &Fq) -> (Choice, Fq) {
+        // This is the constant-time `sqrt_ratio` routine for q = 1 (mod 4)
+        // from Appendix F.2.1.1 of RFC 9380, "Hashing to Elliptic Curves",
+        // with c1 = s and c2 = t, where t * 2^s + 1 = q with t odd. Its
+        // loop bounds depend only on s, so it runs in constant time.
+
+        // tv2 = v^(2^s - 1)
+        let mut tv2 = *v;
+        for _ in 1..S {
+            tv2 = tv2.square() * v;
+        }
 
-    fn from_u512(limbs: [u64; 8]) -> Fq {
-        // We reduce an arbitrary 512-bit number by decomposing it into two 256-bit digits
-        // with the higher bits multiplied by 2^256. Thus, we perform two reductions
-        //
-        // 1. the lower bits are multiplied by R^2, as normal
-        // 2. the upper bits are multiplied by R^2 * 2^256 = R^3
-        //
-        // and computing their sum in the field. It remains to see that arbitrary 256-bit
-        // numbers can be placed into Montgomery form safely using the reduction. The
-        // reduction works so long as the product is less than R=2^256 multipled by
-        // the modulus. This holds because for any `c` smaller than the modulus, we have
-        // that (2^256 - 1)*c is an acceptable product for the reduction. Therefore, the
-        // reduction always works so long as `c` is in the field; in this case it is either the
-        // constant `R2` or `R3`.
-        let d1 = Fq([limbs[4], limbs[5], limbs[6], limbs[7]]) - &MODULUS;
-        let d0 = Fq([limbs[0], limbs[1], limbs[2], limbs[3]]) - &MODULUS;
-        // Convert to Montgomery form
-        d1 * R3 + d0 * R2
-    }
+        // tv5 = (u * v^(2^(s+1) - 1))^((t - 1) // 2) * v^(2^s - 1)
+        let tv3 = tv2.square() * v;
+        let tv5 = (u * &tv3).pow_vartime(&[
+            0x7fff2dff7fffffff,
+            0x04d0ec02a9ded201,
+            0x94cebea4199cec04,
+            0x0000000039f6d3a9,
+        ]) * &tv2;
+
+        // tv3 is now a candidate square root of u / v up to a 2^s root
+        // of unity, and tv4 = tv3^2 * v / u is that root of unity.
+        let tv2 = tv5 * v;
+        let mut tv3 = tv5 * u;
+        let mut tv4 = tv3 * &tv2;
+
+        // u / v is a square exactly when tv4 has order dividing 2^(s-1).
+        let mut tv5 = tv4;
+        for _ in 1..S {
+            tv5 = tv5.square();
+        }
+        let is_square = tv5.ct_eq(&Fq::one());
+
+        // If u / v is not a square we find a root of g * u / v instead,
+        // using the fact that g^t is a primitive 2^s root of unity.
+        let mut tv1 = ROOT_OF_UNITY;
+        tv3 = Fq::conditional_select(&(tv3 * GENERATOR_POW_T_PLUS_1_OVER_2), &tv3, is_square);
+        tv4 = Fq::conditional_select(&(tv4 * &tv1), &tv4, is_square);
+
+        // Cancel the remaining root of unity one bit at a time.
+        for i in (2..=S).rev() {
+            let mut tv5 = tv4;
+            for _ in 2..i {
+                tv5 = tv5.square();
+            }
+            let e1 = tv5.ct_eq(&Fq::one());
+            let tv2 = tv3 * &tv1;
+            tv1 = tv1.square();
+            let tv5 = tv4 * &tv1;
+            tv3 = Fq::conditional_select(&tv2, &tv3, e1);
+            tv4 = Fq::conditional_select(&tv5, &tv4, e1);
+        }
 
-    /// Squares this element.
-    pub fn square(&self) -> Fq {
-        let (r1, carry) = mac(0, self.0[0], self.0[1], 0);
-        let (r2, carry) = mac(0, self.0[0], self.0[2], carry);
-        let (r3, r4) = mac(0, self.0[0], self.0[3], carry);
-
-        let (r3, carry) = mac(r3, self.0[1], self.0[2], 0);
-        let (r4, r5) = mac(r4, self.0[1], self.0[3], carry);
-
-        let (r5, r6) = mac(r5, self.0[2], self.0[3], 0);
-
-        let r7 = r6 >> 63;
-        let r6 = (r6 << 1) | (r5 >> 63);
-        let r5 = (r5 << 1) | (r4 >> 63);
-        let r4 = (r4 << 1) | (r3 >> 63);
-        let r3 = (r3 << 1) | (r2 >> 63);
-        let r2 = (r2 << 1) | (r1 >> 63);
-        let r1 = r1 << 1;
-
-        let (r0, carry) = mac(0, self.0[0], self.0[0], 0);
-        let (r1, carry) = adc(0, r1, carry);
-        let (r2, carry) = mac(r2, self.0[1], self.0[1], carry);
-        let (r3, carry) = adc(0, r3, carry);
-        let (r4, carry) = mac(r4, self.0[2], self.0[2], carry);
-        let (r5, carry) = adc(0, r5, carry);
-        let (r6, carry) = mac(r6, self.0[3], self.0[3], carry);
-        let (r7, _) = adc(0, r7, carry);
-
-        Fq::montgomery_reduce(r0, r1, r2, r3, r4, r5, r6, r7)
-    }
+        // The routine above reports u = 0 as a non-square; fix that up
+        // when v is nonzero.
+        let u_is_zero = u.ct_eq(&Fq::zero());
+        let v_is_zero = v.ct_eq(&Fq::zero());
 
-    fn legendre_symbol_vartime(&self) -> Self {
-        // Legendre symbol computed via Euler's criterion:
-        // self^((q - 1) // 2)
-        self.pow_vartime(&[
-            0x7fffffff80000000,
-            0xa9ded2017fff2dff,
-            0x199cec0404d0ec02,
-            0x39f6d3a994cebea4,
-        ])
+        (is_square | (u_is_zero & !v_is_zero), tv3)
     }
 
     /// Computes the square root of this element, if it exists.
     ///
     /// **This operation is variable time.**
     pub fn sqrt_vartime(&self) -> Option<Self> {
-        let legendre_symbol = self.legendre_symbol_vartime();
+        let legendre_symbol = self.legendre_vartime();
 
-        if legendre_symbol == Self::zero() {
+        if legendre_symbol == 0 {
             Some(*self)
-        } else if legendre_symbol != Self::one() {
+        } else if legendre_symbol != 1 {
             None
         } else {
             // Tonelli-Shank's algorithm for q mod 16 = 1
@@ -414,45 +304,13 @@ impl Fq {
         }
     }
 
-    /// Exponentiates `self` by `by`, where `by` is a
-    /// little-endian order integer exponent.
-    pub fn pow(&self, by: &[u64; 4]) -> Self {
-        let mut res = Self::one();
-        for e in by.iter().rev() {
-            for i in (0..64).rev() {
-                res = res.square();
-                let mut tmp = res;
-                tmp.mul_assign(self);
-                res.conditional_assign(&tmp, (((*e >> i) & 0x1) as u8).into());
-            }
-        }
-        res
-    }
-
-    /// Exponentiates `self` by `by`, where `by` is a
-    /// little-endian order integer exponent.
-    ///
-    /// **This operation is variable time with respect
-    /// to the exponent.** If the exponent is fixed,
-    /// this operation is effectively constant time.
-    pub fn pow_vartime(&self, by: &[u64; 4]) -> Self {
-        let mut res = Self::one();
-        for e in by.iter().rev() {
-            for i in (0..64).rev() {
-                res = res.square();
-
-                if ((*e >> i) & 1) == 1 {
-                    res.mul_assign(self);
-                }
-            }
-        }
-        res
-    }
-
     /// Exponentiates `self` by q - 2, which has the
     /// effect of inverting the element if it is
-    /// nonzero.
-    pub fn invert_nonzero(&self) -> Self {
+    /// nonzero. This was the inversion routine before
+    /// `invert_nonzero` switched to safegcd, and is kept
+    /// as a reference implementation for testing.
+    #[cfg(test)]
+    fn invert_nonzero_addition_chain(&self) -> Self {
         #[inline(always)]
         fn square_assign_multi(n: &mut Fq, num_times: usize) {
             for _ in 0..num_times {
@@ -550,58 +408,6 @@ impl Fq {
         t0
     }
 
-    #[inline]
-    fn montgomery_reduce(
-        r0: u64,
-        r1: u64,
-        r2: u64,
-        r3: u64,
-        r4: u64,
-        r5: u64,
-        r6: u64,
-        r7: u64,
-    ) -> Self {
-        // The Montgomery reduction here is based on Algorithm 14.32 in
-        // Handbook of Applied Cryptography
-        // <http://cacr.uwaterloo.ca/hac/about/chap14.pdf>.
-
-        let k = r0.wrapping_mul(INV);
-        let (_, carry) = mac(r0, k, MODULUS.0[0], 0);
-        let (r1, carry) = mac(r1, k, MODULUS.0[1], carry);
-        let (r2, carry) = mac(r2, k, MODULUS.0[2], carry);
-        let (r3, carry) = mac(r3, k, MODULUS.0[3], carry);
-        let (r4, carry2) = adc(r4, 0, carry);
-
-        let k = r1.wrapping_mul(INV);
-        let (_, carry) = mac(r1, k, MODULUS.0[0], 0);
-        let (r2, carry) = mac(r2, k, MODULUS.0[1], carry);
-        let (r3, carry) = mac(r3, k, MODULUS.0[2], carry);
-        let (r4, carry) = mac(r4, k, MODULUS.0[3], carry);
-        let (r5, carry2) = adc(r5, carry2, carry);
-
-        let k = r2.wrapping_mul(INV);
-        let (_, carry) = mac(r2, k, MODULUS.0[0], 0);
-        let (r3, carry) = mac(r3, k, MODULUS.0[1], carry);
-        let (r4, carry) = mac(r4, k, MODULUS.0[2], carry);
-        let (r5, carry) = mac(r5, k, MODULUS.0[3], carry);
-        let (r6, carry2) = adc(r6, carry2, carry);
-
-        let k = r3.wrapping_mul(INV);
-        let (_, carry) = mac(r3, k, MODULUS.0[0], 0);
-        let (r4, carry) = mac(r4, k, MODULUS.0[1], carry);
-        let (r5, carry) = mac(r5, k, MODULUS.0[2], carry);
-        let (r6, carry) = mac(r6, k, MODULUS.0[3], carry);
-        let (r7, _) = adc(r7, carry2, carry);
-
-        // Result may be within MODULUS of the correct value
-        Fq([r4, r5, r6, r7]) - &MODULUS
-    }
-}
-
-impl<'a> From<&'a Fq> for [u8; 32] {
-    fn from(value: &'a Fq) -> [u8; 32] {
-        value.into_bytes()
-    }
 }
 
 #[test]
@@ -744,6 +550,69 @@ fn test_from_bytes_vartime() {
     );
 }
 
+#[test]
+fn test_from_bytes() {
+    assert_eq!(
+        Fq::from_bytes([
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0
+        ]).unwrap(),
+        Fq::zero()
+    );
+
+    assert_eq!(
+        Fq::from_bytes([
+            1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0
+        ]).unwrap(),
+        Fq::one()
+    );
+
+    assert_eq!(
+        Fq::from_bytes([
+            254, 255, 255, 255, 1, 0, 0, 0, 2, 72, 3, 0, 250, 183, 132, 88, 245, 79, 188, 236, 239,
+            79, 140, 153, 111, 5, 197, 172, 89, 177, 36, 24
+        ]).unwrap(),
+        R2
+    );
+
+    // -1 should work
+    assert!(
+        bool::from(Fq::from_bytes([
+            0, 0, 0, 0, 255, 255, 255, 255, 254, 91, 254, 255, 2, 164, 189, 83, 5, 216, 161, 9, 8,
+            216, 57, 51, 72, 125, 157, 41, 83, 167, 237, 115
+        ]).is_some())
+    );
+
+    // modulus is invalid
+    assert!(
+        bool::from(Fq::from_bytes([
+            1, 0, 0, 0, 255, 255, 255, 255, 254, 91, 254, 255, 2, 164, 189, 83, 5, 216, 161, 9, 8,
+            216, 57, 51, 72, 125, 157, 41, 83, 167, 237, 115
+        ]).is_none())
+    );
+
+    // Anything larger than the modulus is invalid
+    assert!(
+        bool::from(Fq::from_bytes([
+            2, 0, 0, 0, 255, 255, 255, 255, 254, 91, 254, 255, 2, 164, 189, 83, 5, 216, 161, 9, 8,
+            216, 57, 51, 72, 125, 157, 41, 83, 167, 237, 115
+        ]).is_none())
+    );
+    assert!(
+        bool::from(Fq::from_bytes([
+            1, 0, 0, 0, 255, 255, 255, 255, 254, 91, 254, 255, 2, 164, 189, 83, 5, 216, 161, 9, 8,
+            216, 58, 51, 72, 125, 157, 41, 83, 167, 237, 115
+        ]).is_none())
+    );
+    assert!(
+        bool::from(Fq::from_bytes([
+            1, 0, 0, 0, 255, 255, 255, 255, 254, 91, 254, 255, 2, 164, 189, 83, 5, 216, 161, 9, 8,
+            216, 57, 51, 72, 125, 157, 41, 83, 167, 237, 116
+        ]).is_none())
+    );
+}
+
 #[test]
 fn test_from_u512_zero() {
     assert_eq!(
@@ -944,6 +813,103 @@ fn test_inversion() {
     }
 }
 
+#[test]
+fn test_batch_invert_with_scratch() {
+    let mut values = [R2, Fq::zero(), LARGEST, Fq::one(), Fq::zero(), R3];
+    let expected = [
+        R2.invert_nonzero(),
+        Fq::zero(),
+        LARGEST.invert_nonzero(),
+        Fq::one(),
+        Fq::zero(),
+        R3.invert_nonzero(),
+    ];
+    let mut scratch = [Fq::zero(); 6];
+
+    let all_nonzero = Fq::batch_invert_with_scratch(&mut values, &mut scratch);
+    assert!(!bool::from(all_nonzero));
+    assert_eq!(values, expected);
+
+    let mut values = [R2, LARGEST, R3];
+    let all_nonzero = Fq::batch_invert_with_scratch(&mut values, &mut scratch);
+    assert!(bool::from(all_nonzero));
+    assert_eq!(
+        values,
+        [R2.invert_nonzero(), LARGEST.invert_nonzero(), R3.invert_nonzero()]
+    );
+
+    let all_nonzero = Fq::batch_invert_with_scratch(&mut [], &mut []);
+    assert!(bool::from(all_nonzero));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_batch_invert() {
+    let mut values = vec![];
+    let mut tmp = R2;
+    for i in 0..100 {
+        values.push(if i % 7 == 0 { Fq::zero() } else { tmp });
+        tmp = tmp.square() + R;
+    }
+
+    let expected: alloc::vec::Vec<_> = values.iter().map(|v| v.invert_nonzero()).collect();
+    let all_nonzero = Fq::batch_invert(&mut values);
+    assert!(!bool::from(all_nonzero));
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn test_invert_vartime() {
+    assert!(Fq::zero().invert_vartime().is_none());
+    assert_eq!(Fq::one().invert_vartime().unwrap(), Fq::one());
+    assert_eq!(LARGEST.invert_vartime().unwrap(), LARGEST.invert_nonzero());
+
+    let mut tmp = R2;
+
+    for _ in 0..100 {
+        assert_eq!(tmp.invert_vartime().unwrap(), tmp.invert_nonzero());
+
+        tmp = tmp.square() + R2;
+    }
+}
+
+#[test]
+fn test_invert_nonzero_matches_addition_chain() {
+    assert_eq!(Fq::zero().invert_nonzero(), Fq::zero());
+    assert_eq!(Fq::zero().invert_nonzero_addition_chain(), Fq::zero());
+
+    let mut tmp = R;
+
+    for _ in 0..1000 {
+        assert_eq!(tmp.invert_nonzero(), tmp.invert_nonzero_addition_chain());
+
+        // Square and add R2 so we check something different next time around
+        tmp = tmp.square() + R2;
+    }
+
+    assert_eq!(
+        (-&Fq::one()).invert_nonzero(),
+        (-&Fq::one()).invert_nonzero_addition_chain()
+    );
+    assert_eq!(
+        LARGEST.invert_nonzero(),
+        LARGEST.invert_nonzero_addition_chain()
+    );
+}
+
+#[test]
+fn test_invert() {
+    assert!(bool::from(Fq::zero().invert().is_none()));
+
+    let mut tmp = R2;
+
+    for _ in 0..100 {
+        assert_eq!(tmp.invert().unwrap(), tmp.invert_nonzero());
+
+        tmp.add_assign(&R2);
+    }
+}
+
 #[test]
 fn test_invert_nonzero_is_pow() {
     let q_minus_2 = [
@@ -994,3 +960,154 @@ fn test_sqrt() {
 
     assert_eq!(49, none_count);
 }
+
+#[test]
+fn test_sqrt_matches_vartime() {
+    let mut square = Fq([
+        0x46cd85a5f273077e,
+        0x1d30c47dd68fc735,
+        0x77f656f60beca0eb,
+        0x494aa01bdf32468d,
+    ]);
+
+    let mut none_count = 0;
+
+    for _ in 0..100 {
+        let square_root = square.sqrt();
+        if bool::from(square_root.is_none()) {
+            assert!(square.sqrt_vartime().is_none());
+            none_count += 1;
+        } else {
+            assert_eq!(square_root.unwrap() * square_root.unwrap(), square);
+        }
+        square -= Fq::one();
+    }
+
+    assert_eq!(49, none_count);
+
+    assert_eq!(Fq::zero().sqrt().unwrap(), Fq::zero());
+    assert_eq!(Fq::one().sqrt().unwrap().square(), Fq::one());
+}
+
+#[test]
+fn test_legendre() {
+    assert_eq!(Fq::zero().legendre(), 0);
+    assert_eq!(Fq::zero().legendre_vartime(), 0);
+    assert!(bool::from(Fq::zero().is_square()));
+
+    assert_eq!(Fq::one().legendre(), 1);
+    assert_eq!(Fq::one().legendre_vartime(), 1);
+    assert!(bool::from(Fq::one().is_square()));
+
+    // The multiplicative generator is not a square.
+    assert_eq!(Fq::from(7).legendre(), -1);
+    assert_eq!(Fq::from(7).legendre_vartime(), -1);
+    assert!(!bool::from(Fq::from(7).is_square()));
+
+    let mut tmp = Fq([
+        0x46cd85a5f273077e,
+        0x1d30c47dd68fc735,
+        0x77f656f60beca0eb,
+        0x494aa01bdf32468d,
+    ]);
+
+    let mut non_square_count = 0;
+
+    for _ in 0..100 {
+        let legendre = tmp.legendre();
+        assert_eq!(legendre, tmp.legendre_vartime());
+        assert_eq!(legendre == 1, tmp.sqrt_vartime().is_some());
+        assert_eq!(bool::from(tmp.is_square()), legendre == 1);
+        assert_eq!(tmp.square().legendre_vartime(), 1);
+
+        if legendre == -1 {
+            non_square_count += 1;
+        }
+
+        tmp -= Fq::one();
+    }
+
+    assert_eq!(49, non_square_count);
+}
+
+#[test]
+fn test_sqrt_ratio() {
+    // 7*R mod q
+    let generator = Fq([
+        0x0000000efffffff1,
+        0x17e363d300189c0f,
+        0xff9c57876f8457b0,
+        0x351332208fc5a8c4,
+    ]);
+    assert_eq!(generator, Fq::from(7));
+
+    let mut u = Fq([
+        0x46cd85a5f273077e,
+        0x1d30c47dd68fc735,
+        0x77f656f60beca0eb,
+        0x494aa01bdf32468d,
+    ]);
+    let mut v = R2;
+
+    let mut non_square_count = 0;
+
+    for _ in 0..100 {
+        let (is_square, r) = Fq::sqrt_ratio(&u, &v);
+        let expected = (u * v.invert_nonzero()).sqrt_vartime();
+
+        if bool::from(is_square) {
+            assert!(expected.is_some());
+            assert_eq!(r.square() * v, u);
+        } else {
+            assert!(expected.is_none());
+            assert_eq!(r.square() * v, generator * u);
+            non_square_count += 1;
+        }
+
+        u -= Fq::one();
+        v += R;
+    }
+
+    assert!(non_square_count > 30 && non_square_count < 70);
+
+    let (is_square, r) = Fq::sqrt_ratio(&Fq::zero(), &R2);
+    assert!(bool::from(is_square));
+    assert_eq!(r, Fq::zero());
+
+    let (is_square, r) = Fq::sqrt_ratio(&R2, &Fq::zero());
+    assert!(!bool::from(is_square));
+    assert_eq!(r, Fq::zero());
+
+    let (is_square, r) = Fq::sqrt_ratio(&Fq::zero(), &Fq::zero());
+    assert!(!bool::from(is_square));
+    assert_eq!(r, Fq::zero());
+}
+
+#[test]
+fn test_sum_of_products() {
+    assert_eq!(Fq::sum_of_products(&[], &[]), Fq::zero());
+
+    // The largest element maximizes every product in the accumulator.
+    let a = [LARGEST; 300];
+    let mut expected = Fq::zero();
+    for _ in 0..300 {
+        expected += LARGEST * LARGEST;
+    }
+    assert_eq!(Fq::sum_of_products(&a, &a), expected);
+
+    let mut a = [Fq::zero(); 20];
+    let mut b = [Fq::zero(); 20];
+    let mut expected = Fq::zero();
+    for i in 0..20 {
+        a[i] = Fq::from(i as u64).square() - R2;
+        b[i] = LARGEST * Fq::from(i as u64 + 7);
+        expected += a[i] * b[i];
+    }
+    assert_eq!(Fq::sum_of_products(&a, &b), expected);
+}
+
+#[test]
+#[should_panic]
+fn test_sum_of_products_length_mismatch() {
+    Fq::sum_of_products(&[Fq::one()], &[]);
+}
@@ -1,9 +1,8 @@
-use core::fmt;
-use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+#[cfg(test)]
+use core::ops::{AddAssign, MulAssign};
 
 use byteorder::{ByteOrder, LittleEndian};
-use crate::util::{adc, mac, sbb};
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use subtle::{ConstantTimeEq, CtOption};
 
 /// Represents an element of `GF(r)`.
 // The internal representation of this type is four 64-bit unsigned
@@ -12,49 +11,6 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 #[derive(Clone, Copy, Eq)]
 pub struct Fr(pub(crate) [u64; 4]);
 
-impl fmt::Debug for Fr {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let tmp = self.into_bytes();
-        write!(f, "0x")?;
-        for &b in tmp.iter().rev() {
-            write!(f, "{:02x}", b)?;
-        }
-        Ok(())
-    }
-}
-
-impl From<u64> for Fr {
-    fn from(val: u64) -> Fr {
-        Fr([val, 0, 0, 0]) * R2
-    }
-}
-
-impl ConstantTimeEq for Fr {
-    fn ct_eq(&self, other: &Self) -> Choice {
-        self.0[0].ct_eq(&other.0[0])
-            & self.0[1].ct_eq(&other.0[1])
-            & self.0[2].ct_eq(&other.0[2])
-            & self.0[3].ct_eq(&other.0[3])
-    }
-}
-
-impl PartialEq for Fr {
-    fn eq(&self, other: &Self) -> bool {
-        self.ct_eq(other).unwrap_u8() == 1
-    }
-}
-
-impl ConditionallySelectable for Fr {
-    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
-        Fr([
-            u64::conditional_select(&a.0[0], &b.0[0], choice),
-            u64::conditional_select(&a.0[1], &b.0[1], choice),
-            u64::conditional_select(&a.0[2], &b.0[2], choice),
-            u64::conditional_select(&a.0[3], &b.0[3], choice),
-        ])
-    }
-}
-
 // Constant representing the modulus
 // r = 0x0e7db4ea6533afa906673b0101343b00a6682093ccc81082d0970e5ed6f72cb7
 const MODULUS: Fr = Fr([
@@ -64,107 +20,6 @@ const MODULUS: Fr = Fr([
     0x0e7db4ea6533afa9,
 ]);
 
-impl<'a> Neg for &'a Fr {
-    type Output = Fr;
-
-    #[inline]
-    fn neg(self) -> Fr {
-        // Subtract `self` from `MODULUS` to negate. Ignore the final
-        // borrow because it cannot underflow; self is guaranteed to
-        // be in the field.
-        let (d0, borrow) = sbb(MODULUS.0[0], self.0[0], 0);
-        let (d1, borrow) = sbb(MODULUS.0[1], self.0[1], borrow);
-        let (d2, borrow) = sbb(MODULUS.0[2], self.0[2], borrow);
-        let (d3, _) = sbb(MODULUS.0[3], self.0[3], borrow);
-
-        // `tmp` could be `MODULUS` if `self` was zero. Create a mask that is
-        // zero if `self` was zero, and `u64::max_value()` if self was nonzero.
-        let mask = u64::from((self.0[0] | self.0[1] | self.0[2] | self.0[3]) == 0).wrapping_sub(1);
-
-        Fr([d0 & mask, d1 & mask, d2 & mask, d3 & mask])
-    }
-}
-
-impl Neg for Fr {
-    type Output = Fr;
-
-    #[inline]
-    fn neg(self) -> Fr {
-        -&self
-    }
-}
-
-impl<'a, 'b> Sub<&'b Fr> for &'a Fr {
-    type Output = Fr;
-
-    #[inline]
-    fn sub(self, rhs: &'b Fr) -> Fr {
-        let (d0, borrow) = sbb(self.0[0], rhs.0[0], 0);
-        let (d1, borrow) = sbb(self.0[1], rhs.0[1], borrow);
-        let (d2, borrow) = sbb(self.0[2], rhs.0[2], borrow);
-        let (d3, borrow) = sbb(self.0[3], rhs.0[3], borrow);
-
-        // If underflow occurred on the final limb, borrow = 0xfff...fff, otherwise
-        // borrow = 0x000...000. Thus, we use it as a mask to conditionally add the modulus.
-        let (d0, carry) = adc(d0, MODULUS.0[0] & borrow, 0);
-        let (d1, carry) = adc(d1, MODULUS.0[1] & borrow, carry);
-        let (d2, carry) = adc(d2, MODULUS.0[2] & borrow, carry);
-        let (d3, _) = adc(d3, MODULUS.0[3] & borrow, carry);
-
-        Fr([d0, d1, d2, d3])
-    }
-}
-
-impl<'a, 'b> Add<&'b Fr> for &'a Fr {
-    type Output = Fr;
-
-    #[inline]
-    fn add(self, rhs: &'b Fr) -> Fr {
-        let (d0, carry) = adc(self.0[0], rhs.0[0], 0);
-        let (d1, carry) = adc(self.0[1], rhs.0[1], carry);
-        let (d2, carry) = adc(self.0[2], rhs.0[2], carry);
-        let (d3, _) = adc(self.0[3], rhs.0[3], carry);
-
-        // Attempt to subtract the modulus, to ensure the value
-        // is smaller than the modulus.
-        Fr([d0, d1, d2, d3]) - &MODULUS
-    }
-}
-
-impl<'a, 'b> Mul<&'b Fr> for &'a Fr {
-    type Output = Fr;
-
-    #[inline]
-    fn mul(self, rhs: &'b Fr) -> Fr {
-        // Schoolbook multiplication
-
-        let (r0, carry) = mac(0, self.0[0], rhs.0[0], 0);
-        let (r1, carry) = mac(0, self.0[0], rhs.0[1], carry);
-        let (r2, carry) = mac(0, self.0[0], rhs.0[2], carry);
-        let (r3, r4) = mac(0, self.0[0], rhs.0[3], carry);
-
-        let (r1, carry) = mac(r1, self.0[1], rhs.0[0], 0);
-        let (r2, carry) = mac(r2, self.0[1], rhs.0[1], carry);
-        let (r3, carry) = mac(r3, self.0[1], rhs.0[2], carry);
-        let (r4, r5) = mac(r4, self.0[1], rhs.0[3], carry);
-
-        let (r2, carry) = mac(r2, self.0[2], rhs.0[0], 0);
-        let (r3, carry) = mac(r3, self.0[2], rhs.0[1], carry);
-        let (r4, carry) = mac(r4, self.0[2], rhs.0[2], carry);
-        let (r5, r6) = mac(r5, self.0[2], rhs.0[3], carry);
-
-        let (r3, carry) = mac(r3, self.0[3], rhs.0[0], 0);
-        let (r4, carry) = mac(r4, self.0[3], rhs.0[1], carry);
-        let (r5, carry) = mac(r5, self.0[3], rhs.0[2], carry);
-        let (r6, r7) = mac(r6, self.0[3], rhs.0[3], carry);
-
-        Fr::montgomery_reduce(r0, r1, r2, r3, r4, r5, r6, r7)
-    }
-}
-
-impl_binops_additive!(Fr, Fr);
-impl_binops_multiplicative!(Fr, Fr);
-
 /// INV = -(r^{-1} mod 2^64) mod 2^64
 const INV: u64 = 0x1ba3a358ef788ef9;
 
@@ -192,136 +47,132 @@ const R3: Fr = Fr([
     0x05874f84946737ec,
 ]);
 
-impl Default for Fr {
-    fn default() -> Self {
-        Self::zero()
-    }
-}
+/// 6*R mod r
+pub(crate) const GENERATOR: Fr = Fr([
+    0x720b1b19d49ea8f1,
+    0xbf4aa36101f13a58,
+    0x5fa8cc968193ccbb,
+    0x0e70cbdc7dccf3ac,
+]);
+
+pub(crate) const S: u32 = 1;
+
+/// -R mod r, which is the 2^s root of unity
+/// GENERATOR^t where t * 2^s + 1 = r with t odd.
+pub(crate) const ROOT_OF_UNITY: Fr = Fr([
+    0xaa9f02ab1d6124de,
+    0xb3524a6466112932,
+    0x7342261215ac260b,
+    0x04d6b87b1da259e2,
+]);
+
+impl_montgomery_field!(Fr, MODULUS, INV, R, R2, R3);
 
 impl Fr {
-    pub fn zero() -> Fr {
-        Fr([0, 0, 0, 0])
-    }
+    /// Writes this scalar in radix 16 with signed digits, returning
+    /// `[a_0, ..., a_63]` such that `self = a_0 + a_1 16^1 + ... + a_63 16^63`
+    /// with `-8 <= a_i < 8` for `i < 63`. Since r < 2^252, the final digit
+    /// `a_63` is either `0` or `1`.
+    pub fn to_radix_16(&self) -> [i8; 64] {
+        let bytes = self.into_bytes();
+        let mut output = [0i8; 64];
+
+        // Step 1: change radix.
+        // Convert from radix 256 (bytes) to radix 16 (nibbles)
+        for i in 0..32 {
+            output[2 * i] = (bytes[i] & 15) as i8;
+            output[2 * i + 1] = ((bytes[i] >> 4) & 15) as i8;
+        }
+        // Precondition note: since self < 2^252, output[63] = 0.
 
-    pub fn one() -> Fr {
-        R
-    }
+        // Step 2: recenter coefficients from [0,16) to [-8,8)
+        for i in 0..63 {
+            let carry = (output[i] + 8) >> 4;
+            output[i] -= carry << 4;
+            output[i + 1] += carry;
+        }
+        // Precondition note: output[63] is not recentered. It
+        // increases by carry <= 1, so output[63] is 0 or 1.
 
-    #[inline]
-    pub fn double(&self) -> Fr {
-        self + self
+        output
     }
 
-    /// Attempts to convert a little-endian byte representation of
-    /// a field element into an element of `Fr`, failing if the input
-    /// is not canonical (is not smaller than r).
+    /// Computes the width-`w` non-adjacent form of this scalar, returning
+    /// `[a_0, ..., a_255]` such that `self = a_0 + a_1 2^1 + ... + a_255 2^255`,
+    /// where every nonzero `a_i` is odd with `|a_i| < 2^(w - 1)`, and at most
+    /// one of any `w` consecutive digits is nonzero.
     ///
     /// **This operation is variable time.**
-    pub fn from_bytes_vartime(bytes: [u8; 32]) -> Option<Fr> {
-        let mut tmp = Fr([0, 0, 0, 0]);
-
-        tmp.0[0] = LittleEndian::read_u64(&bytes[0..8]);
-        tmp.0[1] = LittleEndian::read_u64(&bytes[8..16]);
-        tmp.0[2] = LittleEndian::read_u64(&bytes[16..24]);
-        tmp.0[3] = LittleEndian::read_u64(&bytes[24..32]);
-
-        // Check if the value is in the field
-        for i in (0..4).rev() {
-            if tmp.0[i] < MODULUS.0[i] {
-                // Convert to Montgomery form by computing
-                // (a.R^{-1} * R^2) / R = a.R
-                tmp.mul_assign(&R2);
-
-                return Some(tmp);
-            }
-
-            if tmp.0[i] > MODULUS.0[i] {
-                return None;
-            }
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `2 <= w <= 8`.
+    pub fn to_wnaf(&self, w: usize) -> [i8; 256] {
+        assert!((2..=8).contains(&w));
+
+        let bytes = self.into_bytes();
+        let mut x = [0u64; 5];
+        for (limb, chunk) in x.iter_mut().zip(bytes.chunks(8)) {
+            *limb = LittleEndian::read_u64(chunk);
         }
 
-        // Value is equal to the modulus
-        None
-    }
-
-    /// Converts an element of `Fr` into a byte representation in
-    /// little-endian byte order.
-    pub fn into_bytes(&self) -> [u8; 32] {
-        // Turn into canonical form by computing
-        // (a.R) / R = a
-        let tmp = Fr::montgomery_reduce(self.0[0], self.0[1], self.0[2], self.0[3], 0, 0, 0, 0);
+        let width = 1u64 << w;
+        let window_mask = width - 1;
+
+        let mut naf = [0i8; 256];
+        let mut pos = 0;
+        let mut carry = 0;
+        while pos < 256 {
+            // Read the w bits starting at pos, which may straddle two limbs.
+            let limb = pos / 64;
+            let bit = pos % 64;
+            let bit_buf = if bit < 64 - w {
+                x[limb] >> bit
+            } else {
+                (x[limb] >> bit) | (x[limb + 1] << (64 - bit))
+            };
+
+            let window = carry + (bit_buf & window_mask);
+
+            if window & 1 == 0 {
+                // The digit here is zero, and the carry is unchanged.
+                pos += 1;
+                continue;
+            }
 
-        let mut res = [0; 32];
-        LittleEndian::write_u64(&mut res[0..8], tmp.0[0]);
-        LittleEndian::write_u64(&mut res[8..16], tmp.0[1]);
-        LittleEndian::write_u64(&mut res[16..24], tmp.0[2]);
-        LittleEndian::write_u64(&mut res[24..32], tmp.0[3]);
+            // Choose the odd digit congruent to the window mod 2^w that is
+            // closest to zero, carrying into the next window if negative.
+            if window < width / 2 {
+                carry = 0;
+                naf[pos] = window as i8;
+            } else {
+                carry = 1;
+                naf[pos] = (window as i8).wrapping_sub(width as i8);
+            }
 
-        res
-    }
+            pos += w;
+        }
+        // A carry out of the window at pos needs that window to be at least
+        // 2^(w - 1) + 1. Since self < 2^252, this forces pos + w <= 252, so
+        // the final carry lands in naf[252] at the latest and is never lost.
 
-    pub fn from_bytes_wide(bytes: [u8; 64]) -> Fr {
-        Fr::from_u512([
-            LittleEndian::read_u64(&bytes[0..8]),
-            LittleEndian::read_u64(&bytes[8..16]),
-            LittleEndian::read_u64(&bytes[16..24]),
-            LittleEndian::read_u64(&bytes[24..32]),
-            LittleEndian::read_u64(&bytes[32..40]),
-            LittleEndian::read_u64(&bytes[40..48]),
-            LittleEndian::read_u64(&bytes[48..56]),
-            LittleEndian::read_u64(&bytes[56..64]),
-        ])
+        naf
     }
 
-    fn from_u512(limbs: [u64; 8]) -> Fr {
-        // We reduce an arbitrary 512-bit number by decomposing it into two 256-bit digits
-        // with the higher bits multiplied by 2^256. Thus, we perform two reductions
-        //
-        // 1. the lower bits are multiplied by R^2, as normal
-        // 2. the upper bits are multiplied by R^2 * 2^256 = R^3
-        //
-        // and computing their sum in the field. It remains to see that arbitrary 256-bit
-        // numbers can be placed into Montgomery form safely using the reduction. The
-        // reduction works so long as the product is less than R=2^256 multipled by
-        // the modulus. This holds because for any `c` smaller than the modulus, we have
-        // that (2^256 - 1)*c is an acceptable product for the reduction. Therefore, the
-        // reduction always works so long as `c` is in the field; in this case it is either the
-        // constant `R2` or `R3`.
-        let d1 = Fr([limbs[4], limbs[5], limbs[6], limbs[7]]) - &MODULUS;
-        let d0 = Fr([limbs[0], limbs[1], limbs[2], limbs[3]]) - &MODULUS;
-        // Convert to Montgomery form
-        d1 * R3 + d0 * R2
-    }
+    /// Computes the square root of this element, if it exists.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        // Because r = 3 (mod 4)
+        // sqrt can be done with only one exponentiation,
+        // via the computation of  self^((r + 1) // 4) (mod r)
+        let sqrt = self.pow_vartime(&[
+            0xb425c397b5bdcb2e,
+            0x299a0824f3320420,
+            0x4199cec0404d0ec0,
+            0x039f6d3a994cebea,
+        ]);
 
-    /// Squares this element.
-    pub fn square(&self) -> Fr {
-        let (r1, carry) = mac(0, self.0[0], self.0[1], 0);
-        let (r2, carry) = mac(0, self.0[0], self.0[2], carry);
-        let (r3, r4) = mac(0, self.0[0], self.0[3], carry);
-
-        let (r3, carry) = mac(r3, self.0[1], self.0[2], 0);
-        let (r4, r5) = mac(r4, self.0[1], self.0[3], carry);
-
-        let (r5, r6) = mac(r5, self.0[2], self.0[3], 0);
-
-        let r7 = r6 >> 63;
-        let r6 = (r6 << 1) | (r5 >> 63);
-        let r5 = (r5 << 1) | (r4 >> 63);
-        let r4 = (r4 << 1) | (r3 >> 63);
-        let r3 = (r3 << 1) | (r2 >> 63);
-        let r2 = (r2 << 1) | (r1 >> 63);
-        let r1 = r1 << 1;
-
-        let (r0, carry) = mac(0, self.0[0], self.0[0], 0);
-        let (r1, carry) = adc(0, r1, carry);
-        let (r2, carry) = mac(r2, self.0[1], self.0[1], carry);
-        let (r3, carry) = adc(0, r3, carry);
-        let (r4, carry) = mac(r4, self.0[2], self.0[2], carry);
-        let (r5, carry) = adc(0, r5, carry);
-        let (r6, carry) = mac(r6, self.0[3], self.0[3], carry);
-        let (r7, _) = adc(0, r7, carry);
-
-        Fr::montgomery_reduce(r0, r1, r2, r3, r4, r5, r6, r7)
+        // Only return Some if it's the square root.
+        CtOption::new(sqrt, sqrt.square().ct_eq(self))
     }
 
     /// Computes the square root of this element, if it exists.
@@ -346,45 +197,13 @@ impl Fr {
         }
     }
 
-    /// Exponentiates `self` by `by`, where `by` is a
-    /// little-endian order integer exponent.
-    pub fn pow(&self, by: &[u64; 4]) -> Self {
-        let mut res = Self::one();
-        for e in by.iter().rev() {
-            for i in (0..64).rev() {
-                res = res.square();
-                let mut tmp = res;
-                tmp.mul_assign(self);
-                res.conditional_assign(&tmp, (((*e >> i) & 0x1) as u8).into());
-            }
-        }
-        res
-    }
-
-    /// Exponentiates `self` by `by`, where `by` is a
-    /// little-endian order integer exponent.
-    ///
-    /// **This operation is variable time with respect
-    /// to the exponent.** If the exponent is fixed,
-    /// this operation is effectively constant time.
-    pub fn pow_vartime(&self, by: &[u64; 4]) -> Self {
-        let mut res = Self::one();
-        for e in by.iter().rev() {
-            for i in (0..64).rev() {
-                res = res.square();
-
-                if ((*e >> i) & 1) == 1 {
-                    res.mul_assign(self);
-                }
-            }
-        }
-        res
-    }
-
     /// Exponentiates `self` by r - 2, which has the
     /// effect of inverting the element if it is
-    /// nonzero.
-    pub fn invert_nonzero(&self) -> Self {
+    /// nonzero. This was the inversion routine before
+    /// `invert_nonzero` switched to safegcd, and is kept
+    /// as a reference implementation for testing.
+    #[cfg(test)]
+    fn invert_nonzero_addition_chain(&self) -> Self {
         #[inline(always)]
         fn square_assign_multi(n: &mut Fr, num_times: usize) {
             for _ in 0..num_times {
@@ -489,58 +308,6 @@ impl Fr {
         t0
     }
 
-    #[inline]
-    fn montgomery_reduce(
-        r0: u64,
-        r1: u64,
-        r2: u64,
-        r3: u64,
-        r4: u64,
-        r5: u64,
-        r6: u64,
-        r7: u64,
-    ) -> Self {
-        // The Montgomery reduction here is based on Algorithm 14.32 in
-        // Handbook of Applied Cryptography
-        // <http://cacr.uwaterloo.ca/hac/about/chap14.pdf>.
-
-        let k = r0.wrapping_mul(INV);
-        let (_, carry) = mac(r0, k, MODULUS.0[0], 0);
-        let (r1, carry) = mac(r1, k, MODULUS.0[1], carry);
-        let (r2, carry) = mac(r2, k, MODULUS.0[2], carry);
-        let (r3, carry) = mac(r3, k, MODULUS.0[3], carry);
-        let (r4, carry2) = adc(r4, 0, carry);
-
-        let k = r1.wrapping_mul(INV);
-        let (_, carry) = mac(r1, k, MODULUS.0[0], 0);
-        let (r2, carry) = mac(r2, k, MODULUS.0[1], carry);
-        let (r3, carry) = mac(r3, k, MODULUS.0[2], carry);
-        let (r4, carry) = mac(r4, k, MODULUS.0[3], carry);
-        let (r5, carry2) = adc(r5, carry2, carry);
-
-        let k = r2.wrapping_mul(INV);
-        let (_, carry) = mac(r2, k, MODULUS.0[0], 0);
-        let (r3, carry) = mac(r3, k, MODULUS.0[1], carry);
-        let (r4, carry) = mac(r4, k, MODULUS.0[2], carry);
-        let (r5, carry) = mac(r5, k, MODULUS.0[3], carry);
-        let (r6, carry2) = adc(r6, carry2, carry);
-
-        let k = r3.wrapping_mul(INV);
-        let (_, carry) = mac(r3, k, MODULUS.0[0], 0);
-        let (r4, carry) = mac(r4, k, MODULUS.0[1], carry);
-        let (r5, carry) = mac(r5, k, MODULUS.0[2], carry);
-        let (r6, carry) = mac(r6, k, MODULUS.0[3], carry);
-        let (r7, _) = adc(r7, carry2, carry);
-
-        // Result may be within MODULUS of the correct value
-        Fr([r4, r5, r6, r7]) - &MODULUS
-    }
-}
-
-impl<'a> From<&'a Fr> for [u8; 32] {
-    fn from(value: &'a Fr) -> [u8; 32] {
-        value.into_bytes()
-    }
 }
 
 #[test]
@@ -685,6 +452,74 @@ fn test_from_bytes_vartime() {
     );
 }
 
+#[test]
+fn test_from_bytes() {
+    assert_eq!(
+        Fr::from_bytes([
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0
+        ])
+        .unwrap(),
+        Fr::zero()
+    );
+
+    assert_eq!(
+        Fr::from_bytes([
+            1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0
+        ])
+        .unwrap(),
+        Fr::one()
+    );
+
+    assert_eq!(
+        Fr::from_bytes([
+            217, 7, 150, 185, 179, 11, 248, 37, 80, 231, 182, 102, 47, 214, 21, 243, 244, 20, 136,
+            235, 238, 20, 37, 147, 198, 85, 145, 71, 111, 252, 166, 9
+        ])
+        .unwrap(),
+        R2
+    );
+
+    // -1 should work
+    assert!(bool::from(
+        Fr::from_bytes([
+            182, 44, 247, 214, 94, 14, 151, 208, 130, 16, 200, 204, 147, 32, 104, 166, 0, 59, 52,
+            1, 1, 59, 103, 6, 169, 175, 51, 101, 234, 180, 125, 14
+        ]).is_some()
+    ));
+
+    // modulus is invalid
+    assert!(bool::from(
+        Fr::from_bytes([
+            183, 44, 247, 214, 94, 14, 151, 208, 130, 16, 200, 204, 147, 32, 104, 166, 0, 59, 52,
+            1, 1, 59, 103, 6, 169, 175, 51, 101, 234, 180, 125, 14
+        ]).is_none()
+    ));
+
+    // Anything larger than the modulus is invalid
+    assert!(bool::from(
+        Fr::from_bytes([
+            184, 44, 247, 214, 94, 14, 151, 208, 130, 16, 200, 204, 147, 32, 104, 166, 0, 59, 52,
+            1, 1, 59, 103, 6, 169, 175, 51, 101, 234, 180, 125, 14
+        ]).is_none()
+    ));
+
+    assert!(bool::from(
+        Fr::from_bytes([
+            183, 44, 247, 214, 94, 14, 151, 208, 130, 16, 200, 204, 147, 32, 104, 166, 0, 59, 52,
+            1, 1, 59, 104, 6, 169, 175, 51, 101, 234, 180, 125, 14
+        ]).is_none()
+    ));
+
+    assert!(bool::from(
+        Fr::from_bytes([
+            183, 44, 247, 214, 94, 14, 151, 208, 130, 16, 200, 204, 147, 32, 104, 166, 0, 59, 52,
+            1, 1, 59, 103, 6, 169, 175, 51, 101, 234, 180, 125, 15
+        ]).is_none()
+    ));
+}
+
 #[test]
 fn test_from_u512_zero() {
     assert_eq!(
@@ -885,6 +720,103 @@ fn test_inversion() {
     }
 }
 
+#[test]
+fn test_batch_invert_with_scratch() {
+    let mut values = [R2, Fr::zero(), LARGEST, Fr::one(), Fr::zero(), R3];
+    let expected = [
+        R2.invert_nonzero(),
+        Fr::zero(),
+        LARGEST.invert_nonzero(),
+        Fr::one(),
+        Fr::zero(),
+        R3.invert_nonzero(),
+    ];
+    let mut scratch = [Fr::zero(); 6];
+
+    let all_nonzero = Fr::batch_invert_with_scratch(&mut values, &mut scratch);
+    assert!(!bool::from(all_nonzero));
+    assert_eq!(values, expected);
+
+    let mut values = [R2, LARGEST, R3];
+    let all_nonzero = Fr::batch_invert_with_scratch(&mut values, &mut scratch);
+    assert!(bool::from(all_nonzero));
+    assert_eq!(
+        values,
+        [R2.invert_nonzero(), LARGEST.invert_nonzero(), R3.invert_nonzero()]
+    );
+
+    let all_nonzero = Fr::batch_invert_with_scratch(&mut [], &mut []);
+    assert!(bool::from(all_nonzero));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_batch_invert() {
+    let mut values = vec![];
+    let mut tmp = R2;
+    for i in 0..100 {
+        values.push(if i % 7 == 0 { Fr::zero() } else { tmp });
+        tmp = tmp.square() + R;
+    }
+
+    let expected: alloc::vec::Vec<_> = values.iter().map(|v| v.invert_nonzero()).collect();
+    let all_nonzero = Fr::batch_invert(&mut values);
+    assert!(!bool::from(all_nonzero));
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn test_invert_vartime() {
+    assert!(Fr::zero().invert_vartime().is_none());
+    assert_eq!(Fr::one().invert_vartime().unwrap(), Fr::one());
+    assert_eq!(LARGEST.invert_vartime().unwrap(), LARGEST.invert_nonzero());
+
+    let mut tmp = R2;
+
+    for _ in 0..100 {
+        assert_eq!(tmp.invert_vartime().unwrap(), tmp.invert_nonzero());
+
+        tmp = tmp.square() + R2;
+    }
+}
+
+#[test]
+fn test_invert_nonzero_matches_addition_chain() {
+    assert_eq!(Fr::zero().invert_nonzero(), Fr::zero());
+    assert_eq!(Fr::zero().invert_nonzero_addition_chain(), Fr::zero());
+
+    let mut tmp = R;
+
+    for _ in 0..1000 {
+        assert_eq!(tmp.invert_nonzero(), tmp.invert_nonzero_addition_chain());
+
+        // Square and add R2 so we check something different next time around
+        tmp = tmp.square() + R2;
+    }
+
+    assert_eq!(
+        (-&Fr::one()).invert_nonzero(),
+        (-&Fr::one()).invert_nonzero_addition_chain()
+    );
+    assert_eq!(
+        LARGEST.invert_nonzero(),
+        LARGEST.invert_nonzero_addition_chain()
+    );
+}
+
+#[test]
+fn test_invert() {
+    assert!(bool::from(Fr::zero().invert().is_none()));
+
+    let mut tmp = R2;
+
+    for _ in 0..100 {
+        assert_eq!(tmp.invert().unwrap(), tmp.invert_nonzero());
+
+        tmp.add_assign(&R2);
+    }
+}
+
 #[test]
 fn test_invert_nonzero_is_pow() {
     let r_minus_2 = [
@@ -936,3 +868,117 @@ fn test_sqrt() {
 
     assert_eq!(47, none_count);
 }
+
+#[test]
+fn test_sqrt_matches_vartime() {
+    let mut square = Fr([
+        // r - 2
+        0xd0970e5ed6f72cb5,
+        0xa6682093ccc81082,
+        0x06673b0101343b00,
+        0x0e7db4ea6533afa9,
+    ]);
+
+    let mut none_count = 0;
+
+    for _ in 0..100 {
+        let square_root = square.sqrt();
+        if bool::from(square_root.is_none()) {
+            assert!(square.sqrt_vartime().is_none());
+            none_count += 1;
+        } else {
+            assert_eq!(square_root.unwrap() * square_root.unwrap(), square);
+        }
+        square -= Fr::one();
+    }
+
+    assert_eq!(47, none_count);
+
+    assert_eq!(Fr::zero().sqrt().unwrap(), Fr::zero());
+    assert_eq!(Fr::one().sqrt().unwrap().square(), Fr::one());
+}
+
+#[test]
+fn test_sum_of_products() {
+    assert_eq!(Fr::sum_of_products(&[], &[]), Fr::zero());
+
+    // The largest element maximizes every product in the accumulator.
+    let a = [LARGEST; 300];
+    let mut expected = Fr::zero();
+    for _ in 0..300 {
+        expected += LARGEST * LARGEST;
+    }
+    assert_eq!(Fr::sum_of_products(&a, &a), expected);
+
+    let mut a = [Fr::zero(); 20];
+    let mut b = [Fr::zero(); 20];
+    let mut expected = Fr::zero();
+    for i in 0..20 {
+        a[i] = Fr::from(i as u64).square() - R2;
+        b[i] = LARGEST * Fr::from(i as u64 + 7);
+        expected += a[i] * b[i];
+    }
+    assert_eq!(Fr::sum_of_products(&a, &b), expected);
+}
+
+#[test]
+#[should_panic]
+fn test_sum_of_products_length_mismatch() {
+    Fr::sum_of_products(&[Fr::one()], &[]);
+}
+
+#[test]
+fn test_to_radix_16() {
+    for scalar in &[Fr::zero(), Fr::one(), -Fr::one(), LARGEST, R2, Fr::from(0x8888)] {
+        let digits = scalar.to_radix_16();
+
+        let mut acc = Fr::zero();
+        for digit in digits.iter().rev() {
+            acc *= Fr::from(16);
+            let abs = Fr::from(u64::from(digit.unsigned_abs()));
+            acc += if *digit < 0 { -abs } else { abs };
+        }
+        assert_eq!(acc, *scalar);
+
+        for digit in digits[..63].iter() {
+            assert!(*digit >= -8 && *digit < 8);
+        }
+        assert!(digits[63] == 0 || digits[63] == 1);
+    }
+}
+
+#[test]
+fn test_to_wnaf() {
+    for w in 2..=8 {
+        for scalar in &[Fr::zero(), Fr::one(), -Fr::one(), LARGEST, R2, Fr::from(0x8888)] {
+            let naf = scalar.to_wnaf(w);
+
+            let mut acc = Fr::zero();
+            for digit in naf.iter().rev() {
+                acc = acc.double();
+                let abs = Fr::from(u64::from(digit.unsigned_abs()));
+                acc += if *digit < 0 { -abs } else { abs };
+            }
+            assert_eq!(acc, *scalar);
+            assert!(naf[253..].iter().all(|digit| *digit == 0));
+
+            let mut last_nonzero = None;
+            for (i, digit) in naf.iter().enumerate() {
+                if *digit != 0 {
+                    assert!(*digit % 2 != 0);
+                    assert!(i32::from(digit.unsigned_abs()) < (1 << (w - 1)));
+                    if let Some(last) = last_nonzero {
+                        assert!(i - last >= w);
+                    }
+                    last_nonzero = Some(i);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_to_wnaf_width_too_large() {
+    Fr::one().to_wnaf(9);
+}
@@ -0,0 +1,1722 @@
+//! The precomputed `BasepointTable` for `SubgroupPoint::generator`, in
+//! Montgomery form. This file is generated by `doc/derive/generator.py`.
+
+use crate::window::{NafLookupTable8, WindowTable};
+use crate::{AffineNielsPoint, BasepointTable, Fq};
+
+const fn niels(v_plus_u: [u64; 4], v_minus_u: [u64; 4], t2d: [u64; 4]) -> AffineNielsPoint {
+    AffineNielsPoint {
+        v_plus_u: Fq(v_plus_u),
+        v_minus_u: Fq(v_minus_u),
+        t2d: Fq(t2d),
+    }
+}
+
+/// The full generator, of order `8r`, in affine coordinates `(u, v)`.
+#[cfg(test)]
+pub(crate) const FULL_GENERATOR: ([u64; 4], [u64; 4]) = (
+    [
+        0x50c87a58c166eca5,
+        0x8046fd74c0051afc,
+        0x406355ee695b0493,
+        0x0d5a8d931bdc7e0a,
+    ],
+    [
+        0x00000017ffffffe8,
+        0x26389fb800276018,
+        0x3293bf3f18d3bf80,
+        0x21b85034193c413b,
+    ],
+);
+
+/// The standard generator of the prime-order subgroup, `8` times
+/// `FULL_GENERATOR`, in affine coordinates `(u, v)`.
+pub(crate) const GENERATOR: ([u64; 4], [u64; 4]) = (
+    [
+        0x264ab2ae27790d7a,
+        0x7715419fe4328d1b,
+        0x26e742fccd3474ae,
+        0x0edae7e0e475434b,
+    ],
+    [
+        0x30b42f35b6518e59,
+        0x599e51c9ec7ab10a,
+        0x3798281a9e12a20f,
+        0x30af1cc0df805b82,
+    ],
+);
+
+/// The table for `GENERATOR`.
+#[rustfmt::skip]
+pub(crate) static GENERATOR_TABLE: BasepointTable = BasepointTable {
+    // [G, 2G, ..., 8G] * 16^(2i) for 0 <= i < 32
+    windows: [
+        WindowTable([
+            niels(
+                [0x56fee1e3ddca9bd3, 0xd0b39369d0ad3e25, 0x5e7f6b176b4716bd, 0x3f8a04a1c3f59ecd],
+                [0x0a697c878ed880df, 0xe289102a084823ef, 0x10b0e51dd0de2d60, 0x21d434dffb0b1837],
+                [0x7248150cab48d0b6, 0x7c8e586f93b30f3d, 0x1ec8a19f5f644691, 0x32e93209868bb8a5],
+            ),
+            niels(
+                [0x0304bc3ac30e6b76, 0xc310ecaac53ef10b, 0xdeb773c677cdcff3, 0x02bc27137bef0518],
+                [0xe7d5fc80c1062e53, 0x257c26ff38d23a4d, 0xe84edb0d977a6b5c, 0x01eb4e0b30ff1086],
+                [0x0459060bfe0d0872, 0xc1fbe33cc1f11398, 0xbfd7360f7491f3e3, 0x268228e888c2462d],
+            ),
+            niels(
+                [0x81e782b5b07816de, 0x408d6963350131a1, 0xfa131201b7dc6d58, 0x257f0c99ad82ecba],
+                [0xde864edb821932a9, 0x6d07839fe9284806, 0x5bf9d490c1c4fbf2, 0x0d5ad281993b3ab2],
+                [0xe1e7530e73e655ac, 0x5029c56e571efd7d, 0xf05624b0d498055a, 0x0ba1b9bdbf01f8a9],
+            ),
+            niels(
+                [0x1bc1aee983914229, 0xd063af0510e70e75, 0xb858e5e8f89e4382, 0x68a91a51a73e43ca],
+                [0x799639ed1e6ace9f, 0xcae134089ca316ef, 0x3873a784a06ccb3e, 0x71f1cb4e6cb0d077],
+                [0xbf8a16eecb71446a, 0x0bbf693a3fa5e8f3, 0x2f96466d72ec23ec, 0x5cc2ed94d0c3a494],
+            ),
+            niels(
+                [0xbc1430355cc5f758, 0x4a89eaf806e8655a, 0xf21e0dc4abe3253e, 0x3fc52ff48015f36a],
+                [0xae52acd4fa458e3a, 0x4b073db9a6c6486b, 0x32de5d2497b6263b, 0x39eea2099073c9be],
+                [0x15eaaee8c8751df9, 0x01d153e98da626b0, 0x662b25c56067edf8, 0x4b15e990b73a3f56],
+            ),
+            niels(
+                [0x3be4aaa5102fe8b2, 0x4d84adc6ec5b312f, 0x4ac4b8f1ff2e75cf, 0x70fb2685d57e02c0],
+                [0x2df6209eeeeedf0a, 0x47a8a66fdacdb33e, 0x9aa3ed4f2225e734, 0x2cb01a9986606ec7],
+                [0x5000d79e6aea0e6c, 0xb5abceb0f560edff, 0x196093a50d69a9e7, 0x674fcc9cf433c960],
+            ),
+            niels(
+                [0x6cf4e8dd025f3be9, 0xca7730e1b3a9fb26, 0x38a2bbeb43db27d4, 0x180282f9674623b7],
+                [0x7cea9049e796d023, 0x9a94e6e031d92cfe, 0x5337e35cbf084230, 0x0fcc9fc83966553b],
+                [0xf85605b4f987d741, 0xf9b0dca6a48fbc25, 0x0f3d73883ec09e20, 0x3a569e6b0653e0fc],
+            ),
+            niels(
+                [0x036d95d6a90fbc04, 0xff64f607beaab333, 0x0e1251d99c0b47e8, 0x35d8093548a8bcb0],
+                [0x7e404301af2b19dd, 0x7fdb58b9fb3d42ac, 0x976342e751abda5f, 0x24fa2fb2175f9266],
+                [0x0701db393af81770, 0x821401fdbd73eb38, 0xf39732f49bae0449, 0x488dc753a7fc4c68],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0xa0536e9c1a583e8f, 0xfe2ac9c4437c0035, 0x4224699d87b17179, 0x03cc7cec9182be2e],
+                [0x6b9c7ba4bed3bff3, 0xee114db2810b1a05, 0x3e356d35279b8198, 0x182f35211c794a8c],
+                [0xc1cb2677f2ee91ed, 0x1951cc696b763833, 0xfb587d6d9de6965d, 0x590330b9d8eb9717],
+            ),
+            niels(
+                [0xd007ac818003f2e4, 0x6c401236a7aac2af, 0x38f679b03801e53b, 0x3a941590c6b3644d],
+                [0x37c0bfb79a6baa0b, 0x872d49e18185b361, 0xa4fbda67e7d090ff, 0x16b553cf65f82e6f],
+                [0x05580491b5467c2b, 0x8bc9a2fe309c80e6, 0x220ae6bab643ee51, 0x2a7f5defb9c52e9a],
+            ),
+            niels(
+                [0x6c02c6543571f536, 0xd2d429974214ef14, 0xa20cc08207aa52b0, 0x1f227a3167135692],
+                [0x2509356d61540511, 0x3a883577cfdc4430, 0x887e0ebc908ff295, 0x3d2dddcb35ef6326],
+                [0x4ea571423d5c701c, 0x9c08bbb3718e42b7, 0xf9fc7fe5e5bc98b5, 0x674ff3e1604be3ab],
+            ),
+            niels(
+                [0x54cbbee6c2040000, 0x679a924d7f1065d8, 0xa6a02b3287d6cec4, 0x60647c3f0e8c66d5],
+                [0x707ebe5e2575dab0, 0x4bbeea32414d208d, 0x381ca875795aa89a, 0x4ad8050ccdb9ea6c],
+                [0xf4befdb0f3ba4f7b, 0x1ae42743fab89c07, 0xf4dee58a447ed3bf, 0x266b24e507ddd85d],
+            ),
+            niels(
+                [0xc0c43d16c370fa5b, 0x6128b4ced04d58b7, 0x0a332d8011fdc0b8, 0x415e9c695bacfa92],
+                [0xecc0a86629223db4, 0x1360c1e63a77310c, 0x0221dfafac0a7931, 0x4510dee465991aa8],
+                [0x4aea9b894a4b4e8b, 0x9a5632cd5dd946d2, 0x999a22dbaef4f2c5, 0x5a1e2ae2f75f7b89],
+            ),
+            niels(
+                [0x070ff3040a736029, 0xffc34ed36110e525, 0xa3b815d451c3315e, 0x042051411887fb61],
+                [0xde78bb19b50d6cc9, 0xa70befa2bd18629a, 0xfa345936aeea32ce, 0x35c57150c456d368],
+                [0xfa30e362f8c0ba93, 0x0e4bcd3e2ada8f8b, 0xcdf6e114e629d2e1, 0x378af3dc60524935],
+            ),
+            niels(
+                [0x6dac260fec37b0e6, 0xed482ba2ce3961e4, 0x9a1941965670c4f9, 0x012567469ce428c2],
+                [0xcdcc4ee86d369257, 0x92f41c19f5ad7ccb, 0x5cff29e37b0d5a96, 0x3127a5158f490ea2],
+                [0x630b2903b8a28fb4, 0x314358c5c58c0d5a, 0x7eb650df2560134d, 0x262a9d7f3987cfc9],
+            ),
+            niels(
+                [0x7d08be050f74a887, 0xa574b585f8315e3b, 0xfe593175fe9ae615, 0x2b90d18de8769c94],
+                [0xe9e2a1ac883ccb14, 0x7f31c2fc3d215d7b, 0x0656d72e9a55f673, 0x21b509a80de631f1],
+                [0x1f1b367ffd76ba34, 0x30575727037d0c02, 0x77aa97ddc2e75a40, 0x0b5595cac4684efb],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0xd40d1efc3941e596, 0xb3ccaf80d8e4cc9e, 0xfd4f895c2270f14f, 0x1e9a2a26c581b182],
+                [0x1a24a9dd137e1f6e, 0xe8cdbc85ba903c54, 0xbd41a6c385061d4a, 0x263ff3dfd00b4f14],
+                [0xd412b6fc5ff227e3, 0x2918d1606213c2da, 0x50634b5a8b0328cd, 0x481f6832a96d578f],
+            ),
+            niels(
+                [0xdf61536150c25308, 0x2c6172ad37f658c3, 0x3bc1fd23a1749dc9, 0x4f30dfa13bf53dc9],
+                [0xfd6ce5beaf170853, 0xa19eb5b2c3c67702, 0x670df8a6ff5266e1, 0x417f3ab181d42290],
+                [0xb276eb9b091e63ad, 0x00cc078a753646a4, 0x939bdb35841d7e49, 0x0969f8e0b2e230bf],
+            ),
+            niels(
+                [0x0cc5240daf36d217, 0x20e8e90fde063f7e, 0x60fa1f83bbba0d46, 0x038441791e293217],
+                [0x68d7467cc21f9673, 0xaed58b885f3cac43, 0x494445ce947b4f91, 0x330cea4b513671df],
+                [0xb97b9a1d83b1cd44, 0x583c18df8b522e44, 0x0b10136c947a1623, 0x1dcfd599fe115f82],
+            ),
+            niels(
+                [0x302a6b8e222d6eea, 0x66ea213b27e7d560, 0xc9073f3ca6bd3ceb, 0x365d736829276782],
+                [0x5c238d51daa47595, 0x1ffa6784f3cdd6de, 0x8960d63e6b68a3cd, 0x15aec0a9e0f1154f],
+                [0xbfbb588de1e26814, 0xd58edc3254f4d99f, 0x6725b572cac1cc35, 0x552b7dbbdfe1c811],
+            ),
+            niels(
+                [0x5569c3d6ad92a282, 0xcd8f8588f4f1d42a, 0xe7accc9b5c703a3b, 0x250232acdc4f143b],
+                [0xa9d414675ca64398, 0xab410d84d6b476aa, 0xda133eb3f6b96dea, 0x739b39f834cee91f],
+                [0xaecdf9779a9885d8, 0xb646d02e312222b4, 0x80858b1cb4d94352, 0x5c0e08be24ae7a50],
+            ),
+            niels(
+                [0xee407f5c4c9ad2d7, 0xd6891c3ff98e7b04, 0x77f2014ede314d3f, 0x020aec7b2c5a384d],
+                [0x941c17685232edf8, 0xe188e1714e7ac411, 0xca03657c3b2107c7, 0x4dcf33314ec58880],
+                [0x420baedee7959995, 0x338b45e94e400186, 0xa68bd6f39317f850, 0x57cf07aab2d07c83],
+            ),
+            niels(
+                [0x8314a2fead26c509, 0x93abfcd9f4b3e4b5, 0xffede755d7cb5d43, 0x0460592eb213be91],
+                [0x903e8cb5a86956da, 0xe860c3a75fdf0681, 0x36b0e145fb3f85fe, 0x38acf11334b48e5a],
+                [0xc2753574b13f7764, 0x701a3a6f5ea41e0c, 0xd6f3ee6f973df49a, 0x2ed78e3e8c09de3f],
+            ),
+            niels(
+                [0xbbb2c0f130186dcf, 0x7c40781197a94386, 0x1a5c13802ed1a052, 0x16cd9a5c7b3da6c2],
+                [0xd465d1067f1a657d, 0xf9d07060c304fbf2, 0x3959fd515ba903c9, 0x5fc85a0a10bb150e],
+                [0x6d8f12912ee08304, 0xa5d615f004016bdb, 0xc0b8f61bc45bb35e, 0x244cf6aa8d194c2a],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x66b295a1b4da175c, 0x80c5be10d77a320b, 0x7350e8fcc1a035a2, 0x30484e11ad8e06c0],
+                [0x8e017fca28b4fcb1, 0x8b7b2b806ca7cfde, 0x2376113b4e3b0315, 0x380cf032dc689c0f],
+                [0xcbbe0e43f7b347b1, 0xcfd663c5a3e2ae97, 0xd34b93854cd5adf4, 0x63b98dafe269dedc],
+            ),
+            niels(
+                [0x0df67a8b3b8f13d6, 0x12ee6f441dbff7e0, 0xec84e68f4ee93b99, 0x2d42c67eb1075062],
+                [0x58ff1d9bb7b3a70f, 0x1446ecf4ea89c0eb, 0xf5b61796a562efa4, 0x2ab7ae2bf0525d61],
+                [0x8b61769ac66e7fe4, 0xc28c312f4e32bfae, 0xf4ea53882cfb712a, 0x196673a6abfeb142],
+            ),
+            niels(
+                [0x2ccf050d6f46ceb8, 0xd223f9cdb53cfa2e, 0xf31700221df2027c, 0x3eaec58617125136],
+                [0x9a2ad2062bc458a1, 0x5d70bacabc6d75fa, 0xd94e93ff375e04dc, 0x20ee3ade68523bf9],
+                [0xd0ef849f658ff373, 0x20f337b492171032, 0x822ae25bf7402134, 0x50064a38f969d75f],
+            ),
+            niels(
+                [0xa1dd7781d766f17d, 0x32bec9a5f0c92102, 0x69ee7db578165c80, 0x64a340a33221417a],
+                [0x6c6f30836dda8524, 0xe617041589de02eb, 0xd5d408797896966f, 0x6b00ff22813e0cd3],
+                [0x8d19c450c0647b68, 0x36ed378ae9ea20f0, 0xc7098257ae2baea5, 0x5c9079213470f88e],
+            ),
+            niels(
+                [0xf7750f36f38f21fd, 0x07c972b9201548f2, 0x91981d6628b7cdb7, 0x490c5ad94eabecbc],
+                [0xd308c9c9284bb996, 0x664a67e71b333368, 0xd327e83ee1911e96, 0x25f446118dbc1762],
+                [0x07b7c0fb1b89866e, 0x2194dac8fff3ec51, 0x18e6a9d5496f4805, 0x2fb17df91823fe42],
+            ),
+            niels(
+                [0x56ebedc1872a110b, 0xf8011da91f45badd, 0x699fd5c59ac05dc2, 0x6f7924f8b7b3c323],
+                [0x70ade9be4603921c, 0x69678c56cfd0b519, 0x8c355735033c3ec5, 0x2e02e43b6914b930],
+                [0xf8b239eb14013124, 0x26ed06a068c8189f, 0xf15187d537823c67, 0x616b0cb94df55530],
+            ),
+            niels(
+                [0x63c9095b7a04f4bc, 0x1d8ecbf01b2537fb, 0x294ade23487c95d8, 0x73799ed3e848a763],
+                [0xf01ab2b2b98cecc9, 0x0fa8d2b02fb73e5e, 0x5f800430a732a394, 0x30aa6e0225b525bb],
+                [0xc6bb55e0e6f4d569, 0xf2cbf68430835ef6, 0xea7e9cf835e1233a, 0x015bf9569ac4bbda],
+            ),
+            niels(
+                [0xbc54d5d7b69634e7, 0x3880f31263db6b3a, 0xa4256ab043ddff2f, 0x0204b3585e572aed],
+                [0x6d84909d0d4cc1b2, 0xc430524d5a7da187, 0x0a8eb2dcb2595614, 0x5053f7e9c8dee8bc],
+                [0xc2a120d43b0b68cb, 0xa053331fff806159, 0x3e5578408c39d8c3, 0x470a80902fd3c945],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x400a8bdab98c04dc, 0x35592238ff2be98a, 0xc43b4001f3fc3e09, 0x62b4011430413778],
+                [0x22b5e6041f48ec4c, 0x1c236db44bf8eec1, 0x79fa6395b05451c8, 0x2d1b84ab5adf7b02],
+                [0xabce0bd34c9a39ff, 0x0334d5f4ff8e070c, 0x46d5dc1cb266edd5, 0x18b88bf7b3ce5a41],
+            ),
+            niels(
+                [0xe7c7de54b1b73cfe, 0x4304de56259b766f, 0x39d39d1d988b6534, 0x55cbfa2909ea6c18],
+                [0x8fc3558d2e1de3bd, 0x1b3e0350ead5d54f, 0xa2eeb07ac3479c59, 0x3aa5b7a871cf249d],
+                [0x16ede853b8c68e5a, 0x76012316612f8e27, 0x499114491b21c60f, 0x0c2cf44c175f701f],
+            ),
+            niels(
+                [0xd075352423a3c7f1, 0x4418ab32089de097, 0x7d6cb691cfd79651, 0x2c7f50cf707f89e0],
+                [0x74b5d0aa6c5e379c, 0x6e8e2ec9222edcf8, 0x3d7f9163de19c5af, 0x71eb4e73939a1839],
+                [0x857a547e118863af, 0x8fdebe1c0596d095, 0x08a5e931cad531f5, 0x1cf33f7a8adf7c5c],
+            ),
+            niels(
+                [0x52e6ab9153765375, 0x74ba6bf60cc651b0, 0x24a1ea12d28cdf31, 0x099b7883a222a2e4],
+                [0x239a655632ecc820, 0x2439f4efed275961, 0xda88addaa1862dcd, 0x10616f1633c9411b],
+                [0xfc790e2337dcb042, 0x78fa238c2e1cac72, 0xc0360197a137961a, 0x013917dd08624c6f],
+            ),
+            niels(
+                [0x0ab7fec41227e7cb, 0xc3772f04b195a5b7, 0xaf4aaeb902ca87de, 0x666f458527c2aa24],
+                [0x9c8220d670494d96, 0x9de59c48c46121d7, 0x96a130cd197a930f, 0x3a143106067f503c],
+                [0x60bdc747fdf8690f, 0x6a53a4d39f24fe24, 0x615619ea92426654, 0x69464f807869512a],
+            ),
+            niels(
+                [0x5a06692c291536ad, 0x7764d3d3fd51a785, 0x9fe6cf34f56f874b, 0x393f1546ed267a6e],
+                [0x06bc4dde537efd80, 0xb37c5e7a598dd3e4, 0xf0f583853f8b2d7b, 0x5d12ab81ba546f8c],
+                [0x2cc1e5a26c1aa25d, 0xbbc1081657c1a848, 0x59fb722a90976f62, 0x0bf2e1206d52d285],
+            ),
+            niels(
+                [0xd148db0a5108cada, 0xf697cc3ba7adc6fa, 0x40b4c5982081786d, 0x6a60e75d4dedcbb6],
+                [0x2e044239896f22b7, 0x4aa7b840e00c0138, 0xa7777bd8a70416cb, 0x3300934a39b184c4],
+                [0x29da291afe16f8a3, 0xa682530e1fae7188, 0x11c6902673a13736, 0x21da4d12ddc2178d],
+            ),
+            niels(
+                [0xbe204d9a8e304563, 0xe7048d748f9562de, 0x394ae76a96277754, 0x02f9c9a6bb1c0ac5],
+                [0xc1cba60b8ab68a37, 0xc2ee9b8092ceef24, 0x8eeec1419697e621, 0x3cb2326281b964ab],
+                [0xb2f60234f4299463, 0xcf53ce08ac81d1b6, 0xe0e5b363f0b46658, 0x075ed0df85f12033],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x8103c4fe2029ac5f, 0x79b69023202febad, 0x7ded2e517f435c55, 0x036cf2416492c277],
+                [0xdd7024ea0b3234ba, 0x0e8ca7faed1ebf30, 0xfbe4ebb99da700e3, 0x1c27af1d1646f8ac],
+                [0xfef71cf808bb93a6, 0xe7582f268ead1613, 0xf8121092fa0d9c51, 0x555f3a74ef413176],
+            ),
+            niels(
+                [0xb61f3850cd295c56, 0xb446f1aac6df3c65, 0xb89b20e3f5c90cb1, 0x3c331109c77ce55f],
+                [0x78970b55cacd1b2d, 0x03f588d8e66adc01, 0xefc70e93645607d7, 0x29fb498a94ba4793],
+                [0x04a1b8b6babb2047, 0x04bf07122bebce90, 0xbc2c5423eef85156, 0x0de651413cf653ca],
+            ),
+            niels(
+                [0x62401e6b1ac584ab, 0x27ac370bffb7f19d, 0x3234807cfef9821e, 0x270e4d78123c5ff7],
+                [0xabaa1647864e09ae, 0x9e6ca320783ab010, 0xafabf1cf22bea6d7, 0x5537d6d591abaa3f],
+                [0xf1ca0c7ec7d7b3d8, 0x0c179baea0431e10, 0xfc805855498eb577, 0x5aa7d056ed517586],
+            ),
+            niels(
+                [0xd389bdbc3d1194e6, 0xb3ca1c701cfcd5c6, 0x728a4f7e3960acfd, 0x34c31aebf250ed25],
+                [0x3df51701616c5d45, 0x8a4bd5af92f73c4e, 0xe82ba7e3e1a54ce7, 0x2fe0cc530402cee5],
+                [0x47953d1c3c145c7a, 0x6e6a3448794d91d3, 0xb1805dc774368529, 0x0be87147a5223c98],
+            ),
+            niels(
+                [0xb30e9074bf29fc44, 0x32ab5be4105bcf04, 0x630421829e50aef0, 0x39f7cc4c296883b5],
+                [0x4967bb5d8add7cde, 0xc0d8c071704817c8, 0x7dd00b0325da39d8, 0x0bfa90b2ee352586],
+                [0xdacbd399516161b1, 0x71869e0a84ff2330, 0xf08f18d36c9d3978, 0x3c0ccb6557152cf5],
+            ),
+            niels(
+                [0xe9242f9c6951f9a6, 0xccbda3d38193da7e, 0x9a9b73b1ef3d1ba9, 0x075b6e4b014a1e6d],
+                [0xf28be4555c56388d, 0x7c8c714afd60acb0, 0xe90d22e1e3454975, 0x3afb8b97d8e0689b],
+                [0xb64b087ed734de3a, 0x502ce9f83fc3ae46, 0x617d7a0e5582a958, 0x3ca70c4404f3176d],
+            ),
+            niels(
+                [0x8b144161dd9f1e6b, 0x2f06d8b5e1368fd2, 0x713b848b53b8f2be, 0x2c675cc312478ae7],
+                [0x4029a8414adc7cc1, 0x213a81a7a75b1062, 0xef5c99b1019b9fec, 0x715d3c8647e25102],
+                [0xf04a2fddec011d40, 0xa9f2bf78644ee996, 0xcb64a3e21f45afe0, 0x0aa95ccb265c4fcb],
+            ),
+            niels(
+                [0x455f5125e22289a1, 0x9610f789a585cc93, 0x6f7dba01a7603091, 0x32a6010e0f354b0b],
+                [0x91bf8b06327af5d6, 0xcb557f5e6318fc62, 0x016449e28088b559, 0x22e3b9961aa3866a],
+                [0x854bb8a6b04a6b6f, 0x4aabb5baf5099aa9, 0x9a07c8e18bd0e970, 0x4e8feedad2593e73],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x914732bb97e2edca, 0xe810debfd865b7dc, 0x02089e2e07d2f222, 0x1f8afc619693bc71],
+                [0x90234678ceefac18, 0xd1c90556596291bd, 0x7fde03430d149a79, 0x50fb14888a622d56],
+                [0xb982e623e1826249, 0x84622ea91fa26c78, 0xceb2366c7509e85e, 0x4c5fd4882071648d],
+            ),
+            niels(
+                [0x9a5eb5b8ec6dc0b6, 0xe8930a6912ae7ff5, 0x303a755aded8e05f, 0x2b396334f765d461],
+                [0x4bd587d77f18a7a2, 0x3b65ccc4bd99b0e5, 0xd5c4dc324ff3eb08, 0x1499b360c0e6f6af],
+                [0xc1bee4b3509ea992, 0xf58457c524c85fe1, 0x641da8811958df6c, 0x4ca90a084209f273],
+            ),
+            niels(
+                [0x850b39d9d428095e, 0xaaec83ee342d7229, 0x67b3afdc44272c6a, 0x6b9ddc90c8eee469],
+                [0xafd72cf2e9bcabd6, 0x8432de32b9d4adad, 0xd3abf4cc39e8a7ce, 0x1f08dc8d941b4cb6],
+                [0xe9cff2b3143c9a2b, 0x3927e65b5f371b91, 0xa48475fe51612744, 0x0788e808b42a17e6],
+            ),
+            niels(
+                [0x0b4f24d4e4285b1d, 0xa3587c3ff2ad7fdf, 0x3ce64a20ab814045, 0x46ebe6575b10142c],
+                [0x442ce8c1e676176f, 0x158baae3a69ff66e, 0xbfc88738262475a6, 0x14a53987ab728deb],
+                [0x928207718974b87d, 0x804f3c330c2bfdce, 0x8ff9989eacf34998, 0x25407075b50ca4b7],
+            ),
+            niels(
+                [0x68c31541a889805f, 0x77448d3767b9f00d, 0x5f178d86a9b7f7d0, 0x17329f979baeba4f],
+                [0x2a0a8a3adc68dcd9, 0x52153a84c668e7d6, 0xb90c7f8456803c58, 0x4b1a593fd79b4404],
+                [0xae21f85e2e082b75, 0x3a2ba58205498cfd, 0xd95ae707f567f692, 0x0dc448f1ca496d32],
+            ),
+            niels(
+                [0xd4417feaa801d312, 0x393a4c23d5dddde1, 0x72328bde4ad788c2, 0x30d81e97ebd4691d],
+                [0xea61de2671b9511a, 0xbc3deaf761da0195, 0xc2653858e148687e, 0x0d8cae56fb67ee50],
+                [0xfdd023cc5fd8f46e, 0x120b5756ba2f4fd2, 0x287eb8896d70a0de, 0x4ca04fbb482bfedc],
+            ),
+            niels(
+                [0xe57611d86ccc0263, 0x6a322e8727ce6cee, 0xec8a9bba13db19e0, 0x690a19a8bddeeb57],
+                [0xca7cff54f6907b02, 0x1ac7d6fde380bf0a, 0x3ae62fb0f0fc86d9, 0x68883ce6a42770cb],
+                [0xb9a9d1c29aa129ae, 0x64ee5a29e9ee794e, 0xfb151e14469785fd, 0x1ba22909d8c478fd],
+            ),
+            niels(
+                [0x3e9ebd0d09bcb61b, 0xe780f6b2fcb4e4af, 0x41159667e41823c0, 0x3056f3ae94613beb],
+                [0xcbef07ed24133157, 0x36c5926f1f6783d2, 0x90132e03149ec5f9, 0x0d206d7e2d065ee4],
+                [0x0c9ccac2a7acd1ff, 0x918dfde4a5607b5f, 0x96700f5a73fe031f, 0x42e3ec86d78a4388],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0xe63d01782ee7de97, 0x7558d125020eddcb, 0x85c51dc6601d0961, 0x52564e4d6bc3334e],
+                [0x4ef5e982fcda3f90, 0x567474e7c271d475, 0xebf29156ae9e1c65, 0x13a434bde2b06f89],
+                [0xccee8a5190880fd0, 0x362e6b46a873ccf1, 0x846f86cb8458f50b, 0x4abfe4a49eedd5b7],
+            ),
+            niels(
+                [0xb78016134465e534, 0x2704f5f3e143732f, 0xa03b06a85152f6b6, 0x0e8df4c61b3ce250],
+                [0x1350b0d9fb59cb59, 0x60ad884c90e72c24, 0x070084e3421df6bb, 0x50be2f1efb01f256],
+                [0x18adf4915b227ff0, 0x57461997966e2c6e, 0x245585a202120760, 0x3a56c3ac730d7cdc],
+            ),
+            niels(
+                [0x831b30a525e8e600, 0xa4d73216d6cb07d7, 0x9c294a8c40a38d6a, 0x0402c9f0b03e9146],
+                [0x17b21b01c0a26ee4, 0xc6b30a92afc0e204, 0x31575323fc9db7cb, 0x1617b2591da02533],
+                [0x26a11eeab91681fd, 0x476801de3e31da00, 0x65b7052925f9885e, 0x10676523d292cc7c],
+            ),
+            niels(
+                [0xd885f498a8dc083e, 0xcd0a15eeb90f955d, 0xb2ea4ddbad5baa20, 0x1ba710e30b48e630],
+                [0xd2dbfd11d487813c, 0x01cfc5c02dc38c78, 0x9d98ea57af1c8150, 0x5914da50ba278ef1],
+                [0x54a7dc976b643f96, 0x238c846faeb957b6, 0x5146cb0e1981bb76, 0x64bbde8b9a306b1e],
+            ),
+            niels(
+                [0xe06eb810556a6517, 0xc6faf0d04dd46b42, 0xc288811966f62330, 0x4b1eb468531fda9b],
+                [0x0cbd699e5a5b8050, 0xb1df5f96211cb7f3, 0xd83b4e12783010f6, 0x6cea430314f1f4a2],
+                [0xfc217686111c4e05, 0xd9e933f5ad7bf557, 0x6d739aec20ce46ea, 0x37d34ef33d2803e3],
+            ),
+            niels(
+                [0x90bb36cfc9135f22, 0xf7295bd466035f96, 0x89c22f047a249c20, 0x483cf33084795151],
+                [0x81e89fb779e75f04, 0x9d5b86c9d2015e98, 0x4c66ced5b53cea9a, 0x57679defc82fa949],
+                [0x03bcf57fcd8b9ff0, 0x320e4647716d9fc8, 0xdaa1365f13c21bd1, 0x4b88f609d0b393cd],
+            ),
+            niels(
+                [0x31ec712ab96012de, 0x17adcb2c990d4d60, 0x3057958b9ff55199, 0x646dc5c4092e246a],
+                [0x5643f034a6042074, 0x97c887da7e69b0a1, 0xcf5d8f08e11b3d5e, 0x53e6cdc3c0bfbbb3],
+                [0x3147bf9e5bc1f4c1, 0xc06363478a46817a, 0xc3749eae16ab29ca, 0x643fd539063a4c40],
+            ),
+            niels(
+                [0x9e1416401309931d, 0x65fa078459947b64, 0xac17d11200152442, 0x587589fa5be30e7d],
+                [0xb12855cb6b83abef, 0x0200b56702dffea3, 0xcf2d69a3daec00fe, 0x29b8ef27dd58751a],
+                [0x40bf431c33bb4e97, 0x2fb25e71d4b5abad, 0x1b789644fa89e3f5, 0x07b0a46d3f63c301],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x2f3cd362c19eea84, 0x017e8792630a29a8, 0xf82dafac25cbd47f, 0x1a4895d03a0b8854],
+                [0x219da0c414aebfa4, 0x0376d0db99a44949, 0xaf2be2c68bb97b3a, 0x28cd6982da49029c],
+                [0xfdb4a2ef5d0b9bce, 0x883df4ab9fed6e7a, 0x4520b2d8c16aea90, 0x0c567a346899a588],
+            ),
+            niels(
+                [0xd15285e3c3d69ca5, 0xd1b7ccba8c1a9415, 0x6424898619ad1b43, 0x3c0e3103663d4ed7],
+                [0x9e96284bab7a15de, 0x2163d6cf14e68fc1, 0x749690b691bd7169, 0x5f2fddae2376f804],
+                [0xb21218d27cd6cb02, 0x60ef70c5bc084059, 0xa128b6a948618912, 0x3286a0716861f64a],
+            ),
+            niels(
+                [0x1e2d24d18d13b527, 0x65e61483a64d9b3a, 0xc28dae79aa87d5de, 0x64ceb18fb9dcd44b],
+                [0xe5beb70c6ab7bf6a, 0x2b708ae6c6aa8b4e, 0x9c405a57f893eb43, 0x24f0b5f9e4672875],
+                [0x72ef098ce0016635, 0xc31eba09f3d2ca52, 0x5293436f6611f07e, 0x1b1c5ec98145469f],
+            ),
+            niels(
+                [0x1db877239e6ed289, 0x183ccc6e90c33425, 0x0b7dee780b7fee9a, 0x5be97317525f0add],
+                [0x1e7b21e70084342d, 0xc4455950f9707939, 0x96c7bc720f77a482, 0x25f464a480402daf],
+                [0x9fd76f10d452fed1, 0xfe0a5dbc69fa3d72, 0x3f3c4b63708e0e72, 0x4c42a70596dce2e0],
+            ),
+            niels(
+                [0x7e6546654b2db76e, 0x3340822e87065a22, 0xa817c28500646de8, 0x2e8da1b3eef635de],
+                [0xf39e26a5662cf665, 0xb0c304535a7b44f9, 0x4b638386eacdb21f, 0x388192296a34b1fc],
+                [0xb44c0c6bd5eb93ef, 0xdd95be5752503dcc, 0xc310f85567d50277, 0x369c13afa03db43e],
+            ),
+            niels(
+                [0xa38629f3b01f051c, 0x0693b8f4386261ca, 0xcc6f0f13dc7b5fc6, 0x0684c17a9df4462d],
+                [0xdd960a0aa654c319, 0xa11ebed2e21e17ff, 0x2e86e39da71a4fad, 0x67a0afdefca355ca],
+                [0x3eced707c5c01a6f, 0x9b8eff3e483b7707, 0x2d2a3d561574c606, 0x17d910d4a41302b3],
+            ),
+            niels(
+                [0x0f0e26745f4dc93a, 0x6ec3062b7fdd2dd2, 0x766bdcc2b591c61e, 0x44449c928eec955b],
+                [0xcbc79dd963454fea, 0x61721aed86dbdec8, 0x8598ffda5b4b046a, 0x01fd42fa633c0adb],
+                [0x7dc4f7273ba7d72e, 0xef0459838a76e788, 0x22dba2176e8ee401, 0x21d99c4dde9f4dea],
+            ),
+            niels(
+                [0xdaaf372a595d7d47, 0xc5a42427dfe9c1f3, 0xaad64d005449b69d, 0x6da284abd83e73f2],
+                [0x151d2154fee2b92e, 0x247fe47d670e10e0, 0x1a7329afde0daba0, 0x4a16701d185ec190],
+                [0x9c01a28ec60db85f, 0x666270deff0c38da, 0xc92cc973cec7e3b1, 0x60f87204999cb479],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x0cd802654f6e6175, 0x365635b88a3d347d, 0x21c3182ebc00b2d1, 0x71559f70b3fa7f93],
+                [0x1798b75affc66169, 0xd9a450373fed9f86, 0xb783eba2250606c9, 0x1a8931574a442d66],
+                [0x8727a1cf8ca4bd20, 0x3aab76e6c6890688, 0xc47507a6ec96b547, 0x2ce030f016343ffc],
+            ),
+            niels(
+                [0x9afcf61ad63d49de, 0x1a360b615837632c, 0xf99148ff2dd4e8d0, 0x3e5dec4763533d17],
+                [0xd5d51cf8106bf787, 0x476915ca20b95bb7, 0xa437585e716f7a3a, 0x16a623f652f70ede],
+                [0xdafdbbfc8c68d861, 0x8104481ef56864ca, 0xe1bc88201b82881b, 0x46adb2d2bc7a433b],
+            ),
+            niels(
+                [0x8a72c9b630ed3992, 0x49a51788ec819835, 0x4a7bac1a6828c89f, 0x56b6bf51e118517c],
+                [0xd3989626e0da263a, 0xb6663a2e675021db, 0x3e24328ad27e7555, 0x39a03daa0cb17379],
+                [0x3e0d1bdf28bf5c0f, 0x166aedd3323a1025, 0x7a453207ffc65fc2, 0x376ba745e58a6b9f],
+            ),
+            niels(
+                [0x068f6ad12058d02b, 0x8d927277aa7be083, 0x84815b16c6648ff0, 0x5a17313d044b018f],
+                [0xebfc27225f264f1f, 0x24ce7d5df98022e1, 0x8e69b4f404ce2d01, 0x635ec6d792c7f9a8],
+                [0xdabd22eaad9e28fa, 0xc1724f3ebde0b5de, 0x2d71ad5ef6b1f7c2, 0x0357951a63a81be9],
+            ),
+            niels(
+                [0xc5d3d1298c87c1e2, 0x34d300f251a1d493, 0x3c451e4c84a67b5c, 0x02fcf252a1130c9d],
+                [0x4514b3ef7de1f703, 0x0d0b9227e12bfaf0, 0x9642631b0e1cdd02, 0x470048152198d9c9],
+                [0x3a9e19365597ce16, 0xb5ab631a73d9dc28, 0x638110c98d831ead, 0x5f7b167e2b08e618],
+            ),
+            niels(
+                [0x0446bb6ce1903edb, 0x96a69a246cf1f885, 0xdc4bce21b293f7a5, 0x3592b3d27afcee41],
+                [0x34133e985cb8e3c6, 0x8513defdc6723846, 0x7ceae974399d6e9f, 0x2045e1c87830c8e8],
+                [0x4caa9721c37e7b1d, 0xa570b9ffc61546c7, 0xc4c3b16e14f19b1c, 0x02277b622781ac05],
+            ),
+            niels(
+                [0xe96ffb596129f399, 0xac2bf708c984a371, 0xe08f212dc60882fa, 0x6ea8fcc8b6a5ca2b],
+                [0xee0677cb8e9efa4c, 0x2a648be5030854e9, 0x5a3fdcbeac99de38, 0x157f1e09f6e50d52],
+                [0x1897203d26ced1e9, 0x586c1b70b66f92f3, 0xf97ae4214bed6a13, 0x49fb8112a20ddf81],
+            ),
+            niels(
+                [0x5f459876de4163a6, 0x4a7cc8fdc394301c, 0xd9b9bccbda95cd9a, 0x513408e74d113b39],
+                [0x02301f3b6d4d6e7b, 0x1bc514924419927a, 0xc9681c501eda7a07, 0x6540f546b281b7db],
+                [0x9ff3fa64213da83a, 0x425cf013a3aa3e84, 0xbf4f4181f3346979, 0x45d8239648766288],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x524c9d27c3291aeb, 0x2f129c1d00f1c4de, 0x26df3cee405ed84e, 0x03ad3c209c9c8e8f],
+                [0xf375da70fadd3688, 0x14f1ebf6bfd18fca, 0x706b25adf25778aa, 0x588725ed7291d62e],
+                [0x76951e82b3a8682d, 0x5d3f84f1cae599b2, 0x06862d359c8b7dd5, 0x72822c7a2069b882],
+            ),
+            niels(
+                [0x4bf634c0cec68c1a, 0xaf6db73a705f6473, 0x85dbf71c66dc5a54, 0x38a39ebf0a0b5c65],
+                [0x0662e0aee4271252, 0xd8e974013612cfc2, 0x9532a07c7a64e2c2, 0x063539746c969f63],
+                [0x7cc3ac888f326ee7, 0x2fe3cb6f90e4b3cc, 0x9b46f8dfe0496bf6, 0x6620b0a75dacd021],
+            ),
+            niels(
+                [0x802dd69208c593d2, 0x703c83a715f3eabc, 0x9db6a2901de8c6bd, 0x3a70857a87d92698],
+                [0x8b293d843995a3bd, 0xdaaa4679940d1317, 0x15bd3afe3ba98d48, 0x172d60e65a8b68d3],
+                [0x99cc056b9fa72f76, 0xb25d29a787104c4b, 0x2c497dfd7fc99525, 0x39a68a82028b4a18],
+            ),
+            niels(
+                [0x4d2144d418436d6d, 0x8dc27807c7892803, 0xb0e01f8a1452d7da, 0x355579e3911eedd2],
+                [0x4baa1bddfb9067ef, 0x7189eafa5732399f, 0x0ae4ce17a02a7f15, 0x5e62da53c339bbf5],
+                [0x89039aa6a0919a80, 0x444f2a31cd39cb6a, 0xd60f19aba6fd443a, 0x467aa818ae58a703],
+            ),
+            niels(
+                [0x4b0e4e017a7cc940, 0xf9f1e254acf39c5c, 0xc3c1c055f8231a39, 0x3444c92230a84bd7],
+                [0x82420f129b92777f, 0x06dd52d65457dfa6, 0x092db0196694fe41, 0x2379c971d2ed33ee],
+                [0x8da363171edd6558, 0x464f66bb2e4458cf, 0x24f17fad06a09e7f, 0x1ab7a2b85bd3a531],
+            ),
+            niels(
+                [0x1d29cacbf7f2f46b, 0x56ab0c82b66c2dc8, 0x448dcffd31a6b626, 0x525f81d9f4f7a206],
+                [0xb3a6c10db34c26d6, 0x1352ddbf2f490bb2, 0xfa54fb74ab376d95, 0x682750b7d21a2448],
+                [0x52c4ecc5d08eb2b6, 0x53c81608bcb2ed6c, 0xb0d048fa62f1c722, 0x17f9c8eadf515f73],
+            ),
+            niels(
+                [0x8dfec7971b6e5916, 0x6e55884607ac9be6, 0xafee73baca1a3e9d, 0x1901478cd2561b93],
+                [0x3ee8aac93c7e90cf, 0x1503a2cb417c942f, 0xbfe637dd7c01f6e8, 0x33614e461f966bf0],
+                [0x755eb929eda943c4, 0xb0a321e19b714c3e, 0xcca9779dbce6b15a, 0x187130237000ce58],
+            ),
+            niels(
+                [0x5d302c172fbd3613, 0x6810e0a4a816d314, 0x25eac7f4cda71232, 0x0fc47188d90031c9],
+                [0xdba954cfb51a1fa9, 0x9007777cdb776d4a, 0xe15c92c38b90d479, 0x1377f27063f36176],
+                [0x3d6f46fc90df9fda, 0x7a1fa2227f06b2d5, 0xaf839b3efc90fd95, 0x2c9abba2d49f04f4],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x0ffa112099ea593b, 0x382ddbb32e4b37fd, 0xa6ca3d2dc22feb25, 0x714aafc698f05bc2],
+                [0x4df1f5449c5a8758, 0xf965391654d04804, 0xb901520d2560c279, 0x1f3ab65d1ee2b9cd],
+                [0x0a0495a6e401efb8, 0x422bc9949be8f17f, 0x9680ce610a25ac64, 0x114b3c0e74d2db9e],
+            ),
+            niels(
+                [0x9bedc43522693ef1, 0x96b9a4b3602300c6, 0xedccd9ebdddb7d4d, 0x10a16caa9625a15b],
+                [0xf9b16635d1d97eb4, 0xa69b6ea24df1f033, 0x95dcff3e29caed24, 0x72c32a5681671ca6],
+                [0x2d8988bb10c845a1, 0xd69cc94b51e372bf, 0x4a889ef3eb1c0439, 0x48e16fed8dfe7342],
+            ),
+            niels(
+                [0x6742ad07aa2c1f68, 0x74461e3462c9b10c, 0x3db70203a20dd9c7, 0x03f7cc414921f658],
+                [0x1124573e0b7a39e2, 0xc9ca6e02ac62432f, 0xb0b395d39e09f8c8, 0x32e1fa71bd883b32],
+                [0xe1aa3681c6080b75, 0x58b288d7dea365a4, 0x5f8d3c3104369872, 0x0a25a8506bc78c23],
+            ),
+            niels(
+                [0xb83fd672194bc14b, 0xdf33d91522346736, 0x473544cf77b6953c, 0x700306d00801a835],
+                [0xe9cd2c28ba9a0f2a, 0xa43c85496c81a57c, 0xb75698a15a68710c, 0x12d5b806cb74a32a],
+                [0xfbd851262eb231a3, 0x98f5efae6945f46f, 0x4f17f0969f477d56, 0x5722e7c938cd508b],
+            ),
+            niels(
+                [0xf82bfa9ab2d53983, 0x91902560a73c8248, 0xcb9d899db2c49619, 0x1765c8c537c660a1],
+                [0x5c76e31af7874075, 0xbfb354ef831e8b31, 0x51df9882ba3d3a76, 0x0d6dc60313e7bda9],
+                [0x0e96b5047d71a7d4, 0x0c52ca7800de7666, 0x7e16934d7c52ac23, 0x0c38551d22dce6bf],
+            ),
+            niels(
+                [0x0e929fc971ed3429, 0x43df22c1a1cecccd, 0xc1b51bd51f3fc903, 0x45d03696b490af8a],
+                [0x0a1a56a1f3fa4f28, 0x8ef62610b0f29d03, 0xa51e0d2353b74693, 0x5e935b3121538e2f],
+                [0x82b50a07dab241ae, 0xea4232450cb95651, 0x85556b81240095d0, 0x6ec5d0460b7fa65f],
+            ),
+            niels(
+                [0x3b372c237e226884, 0x0838b0dedb2abd27, 0x6c788af662a23f9b, 0x1ba852c9e4b56eda],
+                [0xfe7841c6a4bcc609, 0xfa2cb531a66242df, 0x7f936a6dc12b14b3, 0x4569d7967b9c7e0a],
+                [0xe58b35b6eb0749ba, 0x4c373caea427760f, 0x7165fb2b664d3816, 0x081214d3306b6811],
+            ),
+            niels(
+                [0x13b55899b896668d, 0xf05fd1b2c0122f65, 0x5cf9f58382cb566d, 0x24c7d715dbb022d4],
+                [0x50a9c097962e0c93, 0x867ee9ab4f158f9d, 0x5c6f753f28408410, 0x2d4200717aaf621d],
+                [0x6352e5fb0f19e3ac, 0x71646554f226efb5, 0x8075f912379cec96, 0x0e4d1e04b11cc7fb],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0xcad1c6ccf860b441, 0xad3e171c112c7d8e, 0xc93470f39e8cc747, 0x2fba51368da7c314],
+                [0xfbab5541e12f43ca, 0x24f7cde7cc0d28de, 0xadae0247113f8933, 0x67e1f4d1307bd0fb],
+                [0x34981e16d91bd9e7, 0x40f085c805be4b87, 0xd0765523148ad550, 0x5f987f83ac7f6aae],
+            ),
+            niels(
+                [0x566208a62f1ef7cb, 0x4b916957a2b36e71, 0x81652e24b9265264, 0x5ac6092569f08a1e],
+                [0x375d9b617fc27c9e, 0xdf2f45a299cf4431, 0x7416742770df4b4b, 0x3e97d187c2a919a4],
+                [0xe3d25d1c4a1a38e2, 0x89bf03b788c24061, 0x29eb70c5a24ef260, 0x2138bba4de4982fe],
+            ),
+            niels(
+                [0xadee9692df1ce949, 0x7148c9f57dfc7152, 0x35521cbc3e7e85bf, 0x2e5b49dc89c489f3],
+                [0xa440e2aa7605faf0, 0xa9e5f90f00058bb8, 0xee9dffcca1d004c3, 0x00d60b6c4716173a],
+                [0xe21caf2e86ef8762, 0xe47df2850d37f7d0, 0x5939caa73606d876, 0x2c44c8a569de812b],
+            ),
+            niels(
+                [0x53ab0b26797854ba, 0x54916733766056f9, 0xb489f245b5a41aa5, 0x2b6f64ad8cfb8ade],
+                [0x37ee1ad966347b7d, 0xd069e273f27e6d31, 0xd299bf440fc75987, 0x40f5adcee15984d8],
+                [0x5c1c7a5b4c9a5f58, 0x0180a6b8bf143197, 0x11d650c8d4a0b11b, 0x293fa08fff11e933],
+            ),
+            niels(
+                [0x3388279ef8a25551, 0x41988bc0507c54d4, 0x44fe8a88b5d2401c, 0x6d3bb2bf7b8d8b56],
+                [0x1db31f6ec97f0b80, 0xbf159d578d42f130, 0x0cf56a99ed9123f2, 0x153c661a7a1c766b],
+                [0xf3b7c0826e5c4856, 0x0ddb10c5fd05befb, 0x468f39b7e7b4e422, 0x57ea0dd26983ec5f],
+            ),
+            niels(
+                [0x3b4ef68d698573c5, 0x9e3e64d59ddd6ed8, 0x0c9cf034935ecc58, 0x060aec1230366ab7],
+                [0x2671ad88ecdac37e, 0x467bff9e5721a1e0, 0x9d4c9172c051507e, 0x5b4b7276ae42031e],
+                [0xf102f5f38b874faa, 0x4e19b40cab294aa7, 0xe6a1ed789b603d95, 0x2d79d69e56cdb009],
+            ),
+            niels(
+                [0x13019e642e25245e, 0x6e700c73111af3e6, 0x1bae6c2e43a88a2f, 0x4d00972aa7844980],
+                [0xe13b47439029c665, 0x06c63c6b6ca78b02, 0xc19c57f6cddd8707, 0x4c4f5d56b7cbd3a1],
+                [0xef14950587964366, 0xf93b682549f03c58, 0x023bcffeb8af70e0, 0x01eda4dff3c80d4f],
+            ),
+            niels(
+                [0x41307c9aaa5f3bf5, 0x22f7b5b1726a49ae, 0xdb394e3676b2b29d, 0x232b41d318c1f782],
+                [0xd7ce4c5ec18e0730, 0x7606b14622a2fd24, 0x8e6ec01890f1372a, 0x0ff17b8ad9f4a142],
+                [0x86f5a3856a7be3c7, 0x2790ee43acc68dd0, 0xd7016e71f5696a39, 0x07e812f373e349f4],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x7ab61d3c9054a8aa, 0x7ae884f4ed24f71d, 0x108384342fd3285e, 0x562d042db5de3f02],
+                [0x23c6cabb111f92d3, 0x8f74870b77298740, 0x4326aa3e896a0eb7, 0x0329a25d1348595e],
+                [0x4d9de143aa78d56a, 0x81dc91578ebcac5d, 0xace914b16dd68092, 0x2db2f21df68569fa],
+            ),
+            niels(
+                [0xd6f341f4f9d86bcc, 0xa5fa303bf6a0d35f, 0x857244c8310653e8, 0x3ceaf68efc4ddffe],
+                [0x168ba9aff8edbf82, 0x326fa6e1b99ead14, 0x9e438c57eac96783, 0x0ab1bcd0d824466a],
+                [0x04a6ca936df3d682, 0x869da239276eef4a, 0xd4ebdc04825f70ef, 0x11181bf1c9310efb],
+            ),
+            niels(
+                [0x07b3c1a2c9c55100, 0x5d6474158afa8cbc, 0x50b9c321e64e6365, 0x563d832b6e4d6015],
+                [0xfecc1ad3dfa05e15, 0x5d2c6c3da9e29d75, 0x944b4062b3111b1e, 0x5eeca4a659a7a35e],
+                [0xe46a2bbc3638b604, 0x151a54aa5522bd9f, 0x532f5e30c87fbd51, 0x52b4019553d3deff],
+            ),
+            niels(
+                [0x9d0aed3fcf11a84f, 0x0e2d38f3ed3c1074, 0x3df7bd429071eab5, 0x19d7b5f112b21004],
+                [0xe80679379990ef4f, 0xe605ac9d0bdfa517, 0xfea7e5ba4c191c15, 0x20aedf2d0398f2fd],
+                [0xcc793d019013e4db, 0x202b922535caedf3, 0x9def0046a33c3e09, 0x5cdf5d7ed57a3383],
+            ),
+            niels(
+                [0x81344cfe18a7e19c, 0xad106317260e0b0d, 0xbb2869307b44905c, 0x3503e036e7a9edf7],
+                [0x7c5b2da3a960dcb4, 0xba4ababa6b41b5f6, 0x6e35c73cb8f04850, 0x3dd0e89971bd6297],
+                [0x4c55be5801aeddc9, 0x6dbf616a06586cb5, 0x8a2382580b01c7ac, 0x63ccb7f9d8d1727f],
+            ),
+            niels(
+                [0xea852acc584ecfcc, 0x427c6105779db104, 0xb3b991361bcd1314, 0x0139b812ed46c110],
+                [0xcbd22e30c8d05414, 0x83a522b5165dec34, 0x2a3743bb95c836dd, 0x19b6594fe4473f01],
+                [0x8f0e446bdf276fa3, 0x5ee926d0cba6c639, 0x23b634617015d2cd, 0x64feda9eda270797],
+            ),
+            niels(
+                [0xc4df57e68d43320c, 0x487043dd17287920, 0x14a1e340e81f434a, 0x0f683770a55f4edb],
+                [0x86250e10af3004c9, 0xf27b041d1a90da60, 0x4cb48a4d1b398531, 0x4e8852186ff3cced],
+                [0x3d5b1b33d1147c00, 0x48432ba8f84cd907, 0x53d2a52b56c30877, 0x48678c985c60f2f2],
+            ),
+            niels(
+                [0xbae1dc9447ec8289, 0xd9697d8e6c4f990f, 0xa6b6dfea2876c7a5, 0x3d3d88101da27822],
+                [0x8fbc5c490b470954, 0x975cb470aced505f, 0xe782638c67c50fbe, 0x369df2f2882f1bc8],
+                [0xf436c9a29e69e21e, 0x2a564509eaeeff45, 0x547ec028907a7a1e, 0x32d9e0654016df92],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x9b9e98b268d2ec75, 0x42428ff5cc65b6d7, 0x6ee2f9632a62242c, 0x734d3376af0166e1],
+                [0x7b89b0d56318a78c, 0x298c21261f75f8c7, 0x93c1dc49eba52c2b, 0x4615d7ea898328e8],
+                [0x69144acb0210ddec, 0x97e13af1246c7734, 0xe00791db29505339, 0x2311326f92d1ddee],
+            ),
+            niels(
+                [0x9c5ce2fc6400e25a, 0x6787d9bb62589f95, 0x871fca82f6ce7d4f, 0x4753c51f745dee49],
+                [0x75249c15a022ce91, 0x3b53102ebb56cfa1, 0xf5d90ded6da5a358, 0x32d47d924d67b7c2],
+                [0x79af60b35e300502, 0xe2e88fe52744104d, 0x70da72a697a02471, 0x66d271e131d503be],
+            ),
+            niels(
+                [0x611fead3a660d091, 0xb8ad853a1457ce5b, 0xe5a01a6f1c5b42dd, 0x2f3dd0e8d10b7840],
+                [0xeaaec67ba96b1dd1, 0xe2e0664f23501e38, 0x5978e4284fc44ef9, 0x1e2602adc1d97b2e],
+                [0xee6f81cdaf4c03b0, 0x64db755d07b83e9b, 0x4f0e6696a9a25964, 0x4827cd66c47a2b10],
+            ),
+            niels(
+                [0x66164f6b23210869, 0xba434820f8fe33e7, 0x715d904d05003723, 0x2be2499626d7401f],
+                [0xc368abf7355250ac, 0xcdb06115e898c594, 0x8d0e003fe696b3cc, 0x14d06077e9d1c95e],
+                [0x2d352a9702ccdb7c, 0xc8349ddc108cd31c, 0xfeceb910298c1d86, 0x0f303b8bb2108429],
+            ),
+            niels(
+                [0xe5409dce7abe5fd6, 0x79a728938e2e574e, 0xdc2a5c18744da1fc, 0x1501e06937b9b7dc],
+                [0xd5af6c990f247025, 0xf5491ec42b554415, 0xe524435c94bf6132, 0x10b263e98007f608],
+                [0x03f3b2bcc8711cbf, 0xea74039b90ce5dc1, 0x298a4165c8dbd876, 0x11884c43169e81dd],
+            ),
+            niels(
+                [0x60f4528ea47a520f, 0x86643833fe7391db, 0xd7b37a40ea0aa451, 0x28bc8262b679a758],
+                [0x8a458c1a45d25475, 0xeda1ea70a6ee0592, 0xf4bc782c4e527b51, 0x3f18bfeca662518a],
+                [0x0f3a4e2f33008272, 0x9939e5766a1d37ae, 0xf29fbd4bf37d9cc7, 0x23384efd7b1857b8],
+            ),
+            niels(
+                [0x8bc0e3df09cca15e, 0xa3d21a97f0be14cf, 0x946e080a6f42a427, 0x2737627fa9a5ee85],
+                [0x5b0e8ef3dcf0d4eb, 0xf17361fa84fc62bb, 0xef772be6f4e94bfe, 0x08d6df5585923aad],
+                [0xec116863bd97e7db, 0xbd76eb837abf911d, 0xc587afcec1db7ca3, 0x34a24a6b85b7a1e4],
+            ),
+            niels(
+                [0x07a077b3e373fbb8, 0x4799f58946dd7e6a, 0xd6fe784509d93a01, 0x39524572dcff45a7],
+                [0xc0d904ec52e25964, 0x0fd5c46cfbd914b8, 0x378f8ac1b6127ce6, 0x1d6f21662bbc4b2f],
+                [0x510089a6091ea38e, 0xb7f6e7a9ff671ef5, 0x5e37f3f50ac3170e, 0x2b10a04144857f1f],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x80cddc9c12567178, 0xf10222dbfeae7ab1, 0x10b1deb05882061a, 0x662758b83c975681],
+                [0xb0e60d20a4d00903, 0x04024e7f2b696ee4, 0x663ab45db60d299f, 0x17c890196a450dc7],
+                [0x34408269e14b20ac, 0x2f4dc79927291338, 0x1eb26c63453f824c, 0x3fe536ef8593405e],
+            ),
+            niels(
+                [0xe84431073dba7a46, 0x5c2ce003c5b7f587, 0x8243cf1783bbd29b, 0x44e26c9b6d003e77],
+                [0xe228717df11935a2, 0xe94b4a7efd020416, 0x9b776f1442962814, 0x57072092edeab658],
+                [0xc61d180256a121d9, 0x4b7b1490719e3c63, 0x464d179fa983fb39, 0x6d1e7cc14a956b0d],
+            ),
+            niels(
+                [0xfc4fce63cf0d7948, 0xcc642eb90364fc6f, 0xde862173d33d3d31, 0x59d230832816af26],
+                [0xb5cd8134bb38c87d, 0x86148a7286f7152e, 0xdd962b5069c058c4, 0x61437d9f7fe395bd],
+                [0x685295f331170b9b, 0x4fb8afd31656b864, 0x4ad4833a5da29ce5, 0x31a3e7b33bfb8ed0],
+            ),
+            niels(
+                [0x5f99d4bd3baf4a97, 0x07971240849856c9, 0xab665b9606d4331f, 0x16ba10aa4b97090a],
+                [0x769debe3b51a4415, 0x14b255a72d9d51f7, 0xb45c15222c0ad004, 0x4109f9a902ad34d2],
+                [0x1e4ffd8ad9edff2e, 0xd4c51a906ddcb37e, 0x9b8d83f8e972a9b9, 0x0c477d01fb553435],
+            ),
+            niels(
+                [0x230fe619dddc576b, 0x726c52d55ad63425, 0xdc9ae7cec3d230dd, 0x59d701e734c9fd02],
+                [0x8ff16100a40f4a2f, 0xe24f10d01a88c307, 0x8f5666beba2b3598, 0x21526d41878b15c5],
+                [0xa32fb308773cf02e, 0x10bf359a6b07aaa9, 0x3db4c68490d06483, 0x72d02fa37a299f67],
+            ),
+            niels(
+                [0x473851e408737ab4, 0xb9f1e1635ea502fd, 0x672c00c0de40a997, 0x2a526d5a032eedb2],
+                [0xf607467c4859a824, 0xafd257b90c79db54, 0xdcac9bf7197e7b23, 0x18e4ab8417b5d51b],
+                [0xcfad5e1150315e46, 0x6902e0c5ed808a02, 0xacbc2340c14c164b, 0x6f22c9852163b19e],
+            ),
+            niels(
+                [0xfddd57b9c955cafc, 0xb340f791467dcddf, 0xad51f1c4c793ad5a, 0x69854d65e63a329b],
+                [0x67e37482dbe51915, 0xc566b907265ed8d1, 0xb0534309d76825cc, 0x2352f2b830e7e042],
+                [0x31e29f8d71e81099, 0xe61b2bf9dc48529d, 0x2a7e70a73c17a2c9, 0x5eaa4af66900d1dc],
+            ),
+            niels(
+                [0x090540f2058e216d, 0x11003b9619d413f6, 0x9e871eb6c86eae80, 0x452e68c1c95911ae],
+                [0x17a921ade34d7343, 0x1a67b8a99c10587f, 0x9b7e3858bfffbeb4, 0x35a8fd6e501acd5d],
+                [0xa02223914b4ee1a4, 0xa13444d7e9fd4bbd, 0x659b92c41871c057, 0x4850d7dbb0a4a56d],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x17207de0bca81d07, 0xf08792997441773f, 0x04e2f242f8240198, 0x3af2bc9c7b1d659f],
+                [0x78886c74e56ba2dd, 0xe3110d8b06910f4a, 0x7c43ba6f41a204eb, 0x64f5f78fc14ba784],
+                [0x5a1289588bd7f8bf, 0x00c89d12586416d7, 0xf9398d93f347816e, 0x1e7be2e7f86a29df],
+            ),
+            niels(
+                [0xdfba6e6f20dafd01, 0xe947d1ab8c649676, 0xb9f111988c4dcc13, 0x6c2502a7cd8e0dea],
+                [0x1f7ec137c63ce324, 0x09f2323469a2cbb9, 0x4ae6c3dac61c17a6, 0x60f8e6390150b6cd],
+                [0xaa6f13c6b31d99fd, 0x94e2d5fe95693afb, 0xa59d3689a44eb24e, 0x49a1dbfa6bd8db3e],
+            ),
+            niels(
+                [0xb3bf22f646d157fc, 0xdacb515d9de14fc6, 0x6245268b6446c102, 0x1201170bb024ee49],
+                [0x0f5bd3f5d4309d6f, 0x5e3514ac14d53f39, 0x1b64ff00d113987b, 0x040a74d6745ab869],
+                [0x9f6ddd6d562e084e, 0xb85745a0c484f121, 0x245941fc9b76196a, 0x1d456c2ceb5b5edc],
+            ),
+            niels(
+                [0x8a826ed0b4715306, 0xc6ef7924aa39bc67, 0x82f989d2e94f8d95, 0x6cdfcbb7a1b5cabf],
+                [0xedfb85d1624eba32, 0x30ccf57836c56261, 0x17689fa8e52a2495, 0x4b6ffac18758885d],
+                [0x83d87df9f9775313, 0xf99706cca70a9038, 0x5a162159b80d75f4, 0x611779693de9e535],
+            ),
+            niels(
+                [0xf2ccca4a8c95da9f, 0x9dadb18d9bde7f98, 0x91b08f90b1650106, 0x0c07fe0e3151d5c8],
+                [0x9835f13c3d2cca00, 0x7c3f8747bc949e60, 0x5c44fd1971673619, 0x10f2c27c7e4c0913],
+                [0x3c35b976a645de63, 0xa6b240c16f767681, 0xa3c795a84a8ef243, 0x6abeb55423bf607e],
+            ),
+            niels(
+                [0x20ba281b4c5ad9b5, 0x3c2a4fe9f5116dee, 0xe5084266d536914f, 0x1da4375190b7ee10],
+                [0x0960d457cc8e5868, 0xbfe055fb5729ad92, 0x7f2a5aeec9df6efb, 0x575e00dec83c612e],
+                [0xadc4686850f1b329, 0xdbc240b2fdfcb2b6, 0x8b8cef4476a39d7d, 0x05ae301432d52f48],
+            ),
+            niels(
+                [0xc3223aded2e0a042, 0xf3d0ba03b34e9a4a, 0x6b73a5c968966c17, 0x2fbd46eb96303e2c],
+                [0xfb4f23fc3944335a, 0x3e6d4404941a9fc4, 0xef3673edc722145e, 0x30ba87132de08116],
+                [0xef61e8a85eddd1d2, 0xc60aea58e8c09576, 0xe135caad3e856266, 0x0e39d9d30977d1c8],
+            ),
+            niels(
+                [0x21618d407acfc8c9, 0x8d7d28ef1d6cbf09, 0xde78a3946dc48b5b, 0x277cfd4b490a60c3],
+                [0x3c1884fed8a2af35, 0xdb6779f77d1ed869, 0xb6b477736bf2fe89, 0x37cca11a7719f42c],
+                [0x81f33c3b36e20814, 0xd1c0a552faf1b1fd, 0x946149e133df931d, 0x17c76cd54eec5ea3],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x16594daa7d920e8c, 0x0d445736448ffc7a, 0x5869e392fd2c7c31, 0x36253fdf2f4c0ab5],
+                [0x870afe08b4c30509, 0x98e4897ba4452adc, 0x154dbbeb7500a055, 0x3e188bfd839c1ed7],
+                [0xafe67f57f947fcf7, 0xa88e07e296dc98a9, 0x0c1c36960919c08c, 0x4c29d4dd0e5d594a],
+            ),
+            niels(
+                [0xfe41e42fad74997f, 0x91635a2e28950169, 0x4f092021cff44df8, 0x664d140ccf178e79],
+                [0x48a8e595ef8ec349, 0x99f3b6a49667c999, 0xd54bf4af0f6cc4ad, 0x0f359fc585b6a2aa],
+                [0xec7f3d5701be8d0d, 0x665c76521c2254bb, 0x97515766345eb49d, 0x2848aa637199567b],
+            ),
+            niels(
+                [0x6bfdf5ced8eed735, 0x24dbf7f99d458b9a, 0xdf08933a297fa26a, 0x706ceb716f2cb8e3],
+                [0x86c32a7ca10eb86e, 0xbbd1a2be8442d971, 0x46d662c6c20fdbc8, 0x14c1c4f4b02c7d86],
+                [0x9ebf6a6df3818b27, 0xad056177581080aa, 0x3c82722e5024c206, 0x1da7b8b678dde07c],
+            ),
+            niels(
+                [0xe29f056c90bedf09, 0x6f690bd3ff418088, 0xcadf3ac8cd513e59, 0x14e23e0078646e9e],
+                [0xd6316afd112589d5, 0x48507237cc44fda9, 0x71237b8b9d03660f, 0x5f55b7f83302ceaa],
+                [0x454057facd99a855, 0xf70f70f0a46b83bc, 0x94fa4061cec14940, 0x1b6c0563d3831bd1],
+            ),
+            niels(
+                [0xba7c26056405c857, 0x7e788f5ad37509fa, 0xbac7bf5ca27ea8ec, 0x12b788ace03e2cfc],
+                [0x81ab58d14862e866, 0xc9a4740ae4ff0e30, 0xc812302db0471ca7, 0x3e84cbbe31973069],
+                [0x153d308f3445f09a, 0x6862b2cec7b9c3c5, 0x290c0588a1ec8709, 0x06eb82eaa315fd01],
+            ),
+            niels(
+                [0xfb7bf3e7458917b5, 0x9bafa74cbc7d989c, 0xc4c5b7d634c48095, 0x736782cd50c8f42a],
+                [0x34b472af63f2faf3, 0x463ad66ba4bed031, 0xab6469be37d27383, 0x024f144223a5c27e],
+                [0xbb7a9fa1a2f985ad, 0x08d5438d588aec5f, 0x4cbad4c7c710faa7, 0x465820e81c78b07d],
+            ),
+            niels(
+                [0xe76aa29da81a1f89, 0x8c6de3af493468d7, 0x04373a8f657e21cf, 0x03ffae49a796fb0f],
+                [0xf80369462c10344a, 0xb03407850f4f36e4, 0x361fbbeda0273751, 0x01d59a4b6cbb8c9a],
+                [0x99fc62e3fae31835, 0x07ade3975c939438, 0x29dc70c15c7156ea, 0x19eadd555ae397b7],
+            ),
+            niels(
+                [0x86ab590eef90a9a6, 0x92c674c9f4632c9f, 0xc6fe5b02db6489b4, 0x24d51348aa94dca4],
+                [0x89dbd87de96b8a9b, 0x20321a61d4ed089d, 0x0f7f30aa9611727a, 0x1cb129dd5ad2160d],
+                [0xfc6a828751a6197d, 0xd3fbf9541a086bbb, 0xcfc996da85df8e9f, 0x3aaefdfc4cb5fc19],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x14381a83b7bf9c96, 0xfb45b53e907902e0, 0x8490645c8b82c32f, 0x2274c42a24a32f98],
+                [0x97238a78065ce910, 0x5fe287f68820810f, 0xd898f8723a0f5d56, 0x19194a38feec3135],
+                [0x509f74bdd3379aa4, 0x89af2f1d5d82729c, 0x7cec22b0a0840d25, 0x204157fbf9ca70de],
+            ),
+            niels(
+                [0xfbb3f9cdf74f94c0, 0x4c552ac83322610b, 0x62b7726abeb16419, 0x53f0a0cbb04326dc],
+                [0xbf0cd0c094418aef, 0xff56d27c2fedd0d9, 0xfacec874a58fb117, 0x12d576395a2cb56c],
+                [0xe0c89f1d94a96277, 0x766c98c31ddab2ee, 0x789c01029e613c22, 0x12a53b29da624d8c],
+            ),
+            niels(
+                [0x1c4f419d9dcf0ef3, 0xafab58acbc3cf27a, 0xc4d9d3cdfc65ae13, 0x6580d826ed8d9074],
+                [0x0db5accdfb4bed51, 0x62694335d84761b5, 0xedebd60c4925a1c3, 0x533c0958cb34b61b],
+                [0xbd03b7655d1ec939, 0xeafacfdab2cf9476, 0xcd96dcc4ec67a93a, 0x70c26a4f05f48b7f],
+            ),
+            niels(
+                [0x64547fbd7e50653e, 0xe164fa9c2904f99e, 0x5d33759e12ece879, 0x295a285eb089819e],
+                [0xdc8126dc66e808d5, 0xba311bf310027ad8, 0xce7b46be46d1b44b, 0x4e39211753792cf8],
+                [0x37a06fed0a3a68ec, 0x860741432af85f61, 0xa69199696db76281, 0x192248f742c2b0e6],
+            ),
+            niels(
+                [0xe32456cfa8c32d93, 0x73484da9f5afb89a, 0x1fda9140921c5af9, 0x3673aec34ae6e4dc],
+                [0x547763db01e8e135, 0x7ade4b8de747959a, 0x8e6a61e703d4a6c8, 0x5c4f35d17eb7fa6d],
+                [0x778351cd0faa39ce, 0xcc6906ff32ec54e9, 0x18c9c2fbbf8803ff, 0x2412ae6c5698cc05],
+            ),
+            niels(
+                [0x7135f03a72a66049, 0xb672103a44dea641, 0xad236e3920d424df, 0x2d67fd94db85141d],
+                [0x21e6d2f4f346725e, 0x84248eeebafaa051, 0x469a72536206e04c, 0x38cb0b581cfa88f4],
+                [0x617dd6f9334aa844, 0x710f5642e0948383, 0xfc3eb7368f9d4b34, 0x707ab20517e1702b],
+            ),
+            niels(
+                [0xb12af3007a8c653c, 0x729e47475a664568, 0xaa4a4a2ed46786e5, 0x0860b6296610c4ee],
+                [0xb4601310ce26ba72, 0xe808c8ac1d3d2c1a, 0xfc01efd9c0e94769, 0x1c54a42a5d59562f],
+                [0xd6b92abed8daa3da, 0x22e3c1c05e03a3f1, 0x65d8168ef1e4b234, 0x5fd68b8bf67bd0e7],
+            ),
+            niels(
+                [0xe1bb958782291bfc, 0x93c8080d059957ba, 0xddf5e203a3b79351, 0x2c5ff11e31f6976b],
+                [0x7de220256140637e, 0x976011d9be7265e6, 0xbf9dab2a104b5164, 0x5c883f00f56dc8a3],
+                [0xbbf40fd79ae4b49a, 0xe0fc5874f3106ee1, 0x87bbde00b4d2056b, 0x0f1f9e4f2a024b30],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0xee03f969cbec13d0, 0x75199c361443a684, 0xa48d2c0f58286a11, 0x09a7dba20a5fdb92],
+                [0xd30f68de96d4ab9a, 0x8cf2cdd91e3843d3, 0x6392e0945f4f045e, 0x519f7ca634b0666a],
+                [0x677547db1988d7af, 0x937b0d9d6fe8334e, 0xb14a0da32fcfc75c, 0x2915bd5cfdd4acd6],
+            ),
+            niels(
+                [0x5780068c6edb199e, 0x1d869fc837028110, 0xdddedefbb19892a4, 0x415133e620efa412],
+                [0x85688e950c57b7ca, 0x8323978a3ae5ab82, 0xcfb0f92bcfc29ab1, 0x656d3e59a80111ff],
+                [0x55b8bacb82480be4, 0x99995e380dd07897, 0xf18542d370ce5a2a, 0x256ae569caf6de04],
+            ),
+            niels(
+                [0xe0cec0d9f64d13b7, 0xb14788264649dbbf, 0x6759ef814cb2234f, 0x714ad4a98dc040ce],
+                [0xcf0e6a4a6fdd27fc, 0x34ed72be7497b44b, 0x3e368f8bdcfeddde, 0x62c31c9e69e0cb6e],
+                [0xd07b7f13ef56ebd1, 0x7b7acd8e042e6838, 0x20552c95e14a7622, 0x0ecc061cac2adead],
+            ),
+            niels(
+                [0xee5eca396205b397, 0x9f65dfda7cb48151, 0xf61ddb8b27201797, 0x426ef256fbcedcd3],
+                [0xa5d085f1e774fb91, 0x0785b5ded72d7460, 0x85ed5c8bb0ee1cda, 0x12dc984309fce296],
+                [0xa53bdf507285bbfb, 0x85a7b227636337e9, 0x537d5bcd8471b430, 0x4cd608b851c57dbf],
+            ),
+            niels(
+                [0xc766b54fc8b6d4d1, 0x2f8b400af7b1618d, 0x7c773d48f39d8075, 0x372dddd40d461688],
+                [0xd4124a28487d4c09, 0xc5fc616d8fe4aae9, 0x464ba3d5083eeefe, 0x522359ab06fd7323],
+                [0x68a15a0a52a0f1c3, 0xe76e2b63723cbbe1, 0x5060fd5c753f68a5, 0x72e7a67c4b80c19d],
+            ),
+            niels(
+                [0x00b20b3c563fadf7, 0xb1087a0e84cc5470, 0xfe4e8e4dfbf28494, 0x3eaf2466d9d20927],
+                [0xd17098085182bf98, 0x34c041f707b47077, 0x69f4cf20dbcafac1, 0x0f0de9b831edcb13],
+                [0x447a955c8cd26e36, 0xb6a19b13c0ac0df7, 0x8ec0abab0cfdfcb4, 0x60ce079efe328f3e],
+            ),
+            niels(
+                [0x87fdc4a08e1d486d, 0x94cee7f77ec52cb2, 0xb14654b84a7b00b5, 0x2326dec92e77f8cc],
+                [0x8be4784569824893, 0xb8b576436b48d4b1, 0xc178ef4b8193c1bc, 0x67b2258707493d17],
+                [0x6c6c6734fdecb14e, 0x5dfa8d9abdfca252, 0x5cb76c5a7a131e0a, 0x33c6e6ce80b6059b],
+            ),
+            niels(
+                [0xb1abab4e788c8b36, 0x2b286b12d3b93559, 0x71547f5aa7acfa22, 0x56fa51b98558ef73],
+                [0x82fe14f605420d7e, 0x5a3f3b9bb1f45f57, 0x86309587db8bb9c4, 0x5a983abe33e17cb9],
+                [0x1ff2799774580d95, 0xb71dab989a0f7e72, 0x2996ef2630acad8f, 0x47aab2c404f6d48c],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0xca4a97e977a7a6c3, 0x0e74777bc064b52c, 0x63fa4b12a7dc28ed, 0x5807dcf66ff5b857],
+                [0xf50fc457c74a36c7, 0x43646d7b05b82823, 0xde83290c2deab6f6, 0x675bf019ca9bb503],
+                [0x28f09cc37dcb5822, 0x98356403e38a5b6d, 0xd445828bb8c10024, 0x1de528edc52f71e0],
+            ),
+            niels(
+                [0x5acb73eddadd2933, 0x403d09d2a0cb8984, 0x3512af17b12b0e7d, 0x1071a7a3c2fa7632],
+                [0xdd0941c3f7cb3cee, 0xf76336fcaf123950, 0xd03fd254a9ab24af, 0x0fbb4a61d5b1e68f],
+                [0xb7929a0a3dc9ad2c, 0x8a054e11ec9f6165, 0x5ad508cc84276f65, 0x0b8d4f2e04afb18c],
+            ),
+            niels(
+                [0x6dc96e4c8581507f, 0xb5017008af49ac2b, 0xe086c4a1c28573d3, 0x378308bfb075a10b],
+                [0x4b366cfe412f8bc5, 0xfc2a723e6fb9eef3, 0x3594fdcde7f59911, 0x40d08ce3c78dba0e],
+                [0x407b5002b2f7043a, 0x27775bb6b97516f8, 0x858773abe2599412, 0x1fd2f42a3f9704d3],
+            ),
+            niels(
+                [0xb080f0a0bedd8004, 0x4a6433291fe11a72, 0x6747f852028cc741, 0x0dd1675bd1696019],
+                [0x3db565eb6df420e4, 0xc8c3c4cb08af0f34, 0x21d0cf85c9ed0095, 0x628d3c4f0ab2f185],
+                [0x48415253e486f218, 0x6801a216fdf71f59, 0x0c2895713c0163da, 0x1827b18eacd5c17f],
+            ),
+            niels(
+                [0x005b617cdd6b6a8f, 0x6c903f6e83bb9ef7, 0x86fadac9f709fdbc, 0x18e92cf078987ab4],
+                [0x6b7c0271e8644d54, 0xaab24dad07b16c1b, 0x57ba7ff5d7364b5d, 0x18dc28bf70538f86],
+                [0x5908d907be93c727, 0x3efdd070b8cb229c, 0x884b37a60e4c496b, 0x4f1c81935d17fd91],
+            ),
+            niels(
+                [0x12c62a5b7869c81d, 0xf8417b7b475bc974, 0xdf505174f135b570, 0x34cbefba180c5b49],
+                [0x2f98cd44814e9db6, 0x962ab388549d3776, 0xc2691407aaf223d2, 0x080eac521f95bdae],
+                [0x9a26a9f912ba7f3c, 0xb138d121e0c51c5f, 0xe5464f0671bcc52a, 0x449414292ffd71b4],
+            ),
+            niels(
+                [0x644f78c24d68ca16, 0x59a1d62d692e6f98, 0x184dcaba67703e6e, 0x0f3eb511cc1ea84e],
+                [0x3d4dfbd7efdf5563, 0xe666feada644b595, 0xf3211a2dbd48205f, 0x079d4691323bf2cd],
+                [0x0b12475af4f07665, 0xe3af2440754b511d, 0x0ec65642ecc50057, 0x2ec293bc97e2cd99],
+            ),
+            niels(
+                [0x7f5f5ef4e588bb13, 0x020321e50c26ced1, 0x6615d79e57bf889e, 0x59f96ed632101236],
+                [0x9b415d3659d1c4e1, 0x035a065ea07f0376, 0xd27b8f248fa46c4d, 0x0a79c8e5a7ebd63e],
+                [0xa454fe547a1daa3b, 0x0109b228fdf1106c, 0x17c4cbba739f28c3, 0x4198539c76a3b27f],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x832d5d3347f1509a, 0xb5a5c942ba35c07e, 0xf841b7294b57c278, 0x4837cdcbc4f3c2d1],
+                [0xe16042a2e8ce465b, 0x450d1ee50a2d0f84, 0xf5b4a53460320eec, 0x04f5207920ca5481],
+                [0x232a645d08de4dc2, 0x1568ade445d0a8d4, 0xf7a015684b8bbb95, 0x1140a8d3900baac7],
+            ),
+            niels(
+                [0xfcb53d7d22d1b832, 0x25bce4d65685937e, 0x8c3d021d54425839, 0x58009a2fb3997409],
+                [0xced9e9e390f05e02, 0x80bff3c20478a1b5, 0x246cc285c4815a30, 0x225c56f7f9fc02b4],
+                [0x2f4d11c53b1ae473, 0x9fec9ff7cbe6b521, 0x9a51d514236dcc60, 0x1ccd697e31b4ad1a],
+            ),
+            niels(
+                [0xc467096b7b8c713a, 0x55a018f596a0a9b3, 0xbf306e2355ce6758, 0x730fd15cd909a47c],
+                [0xb7f5ad6d358f8558, 0xb676f0eb5fa13b37, 0x87705386c0cb6ee2, 0x2282dc87e1507fc6],
+                [0xd170f48e52a3d47b, 0xb43e2bb0fc73c5b1, 0xabcf4e21b2464918, 0x2dec3ce100815508],
+            ),
+            niels(
+                [0xcae12d336fc5f87d, 0x932528a1c82350ef, 0x325272d92cead14b, 0x1970b6f4100107c5],
+                [0x288af798e8b2009a, 0x5451f3ebd45b1e8e, 0x6ba6d5563c249d98, 0x6efbec727c75a60d],
+                [0x961e5a0a2620dc7f, 0xed2ecab3343c0791, 0xa4069a5737d00dba, 0x4759ba8561e234a5],
+            ),
+            niels(
+                [0x4dcdd3ba97e54704, 0xfbc91e429f8b3773, 0xa20d325158ce68e9, 0x26b8d2596f46af8e],
+                [0xb5fe7c295af97ef4, 0x9c78d8f6b6d97614, 0xdcb4d3eaabc6bc1e, 0x64284d51d98ea79a],
+                [0x37e8152024433ced, 0x06557ba55bfa4a24, 0x460142adc19a2c48, 0x2bd442d9e968d37b],
+            ),
+            niels(
+                [0x874029076bcc0da6, 0xb56fdaeb702f579f, 0xb21d8c34c29f1e46, 0x4eebee56ada612d0],
+                [0x61817c52c73e1fe8, 0x7b52e63d23775044, 0x062b67c091c0b0af, 0x15f1e2c222d40fff],
+                [0x94176b3a074096e4, 0x99db4222cb122764, 0x67f093d34b4ff437, 0x6aa9c61f1f561da3],
+            ),
+            niels(
+                [0x2aae3a26f8de470a, 0x0faf87ca26df74bb, 0x5a3a890ba3b488dd, 0x24abca829c8d1d85],
+                [0x099bfdc5d4f70118, 0x82ba01be3853ae10, 0xac6137fb78b7ecba, 0x34521481e8cfed9c],
+                [0x1ad9fd2348a8e6f2, 0xa0beebd29f6f4279, 0xab32794482242669, 0x4602397f94204a2e],
+            ),
+            niels(
+                [0x0bb1d4fe7189f649, 0x93207edbcffce262, 0xe9a4538de4e012cb, 0x5683a5f8845a8f18],
+                [0xfc596becd68cfd67, 0x5140ef8c7be16fa1, 0xe8ac3f6f40da9ba6, 0x2844b357446c32b6],
+                [0xb3025c6c281b3e72, 0xb70d52ec12855c65, 0x86f870198582caf7, 0x35ed223f64074bfb],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x8100a304c1036b2f, 0x95e3cc577dd44dbe, 0x6ad1a6b2c3a351ad, 0x32875b2e0eecbc90],
+                [0xb7e275d4561b1c05, 0xe76a594658d5b06b, 0x60853fb46b81ff9e, 0x0698d96484acfb3e],
+                [0xc933f2a7db18edc6, 0xfc1bd314a081a83c, 0xfd5bf380f8f7d7f5, 0x4840945b4e128854],
+            ),
+            niels(
+                [0x556e87a77babb49b, 0x10bed0a29bdb1a1c, 0x59a923dd89392ef4, 0x34c22cfd4f2f5e84],
+                [0x9a66c07a7da694d9, 0x7ed0023841631da8, 0x57586690fc98458c, 0x622effe7864f87e3],
+                [0x4dc58865f5a96577, 0x971960eb0f298814, 0x350940d3574a074c, 0x0ca713d70f5e2185],
+            ),
+            niels(
+                [0xc0183d124b274242, 0x753908b84a4543ac, 0x9271bdc29e28b10b, 0x6d16508f1db03642],
+                [0x92dc7ecf095b0b79, 0x1e55f6a141cf39bc, 0x51d181fef8c1f732, 0x2c7a6ca78c7e1974],
+                [0x584b4228643a7bed, 0xa56bf801ce11eac0, 0xfdb514eb9fa04593, 0x39b684b8bcb6e1be],
+            ),
+            niels(
+                [0x51c5dc76743fe3be, 0x29322f0567624393, 0xdc465d753c9fd6d4, 0x37190a8a1dc50947],
+                [0xc974fc9cd448c4f4, 0xd52d9e3b02342c82, 0x7778465d7c66adfc, 0x713a27d2899ef56b],
+                [0xe8d04c162e33ae09, 0xcbfe43b8151e595d, 0x619607d38fe23144, 0x4ca56b6d31397ac5],
+            ),
+            niels(
+                [0xa8154540cc0fb4a1, 0x102399c6b3992443, 0x990c3653fb1a5c56, 0x220d669f51c2e138],
+                [0xc6bb10e5e5a0f2c2, 0x92b5e2a22815ef2f, 0x645a43fb9032961d, 0x3e8031009503831c],
+                [0x5d679c567f10917c, 0x5b38a6598cba6732, 0x1a8ec782f66fa7e3, 0x5141c5de543a786e],
+            ),
+            niels(
+                [0x35de48375a9678a3, 0x74087b4314a20620, 0x9144299df1f19d6b, 0x2f3a8a66211930f6],
+                [0x2487bb7530af76ee, 0xfae1e2891f7da4ec, 0x36c6b973b1492c3e, 0x36fc4162f72b4a92],
+                [0x0a5e0e435f54de07, 0xafe532ad6d4a9b34, 0x90ec45ee590c7ff7, 0x0910bd134c220430],
+            ),
+            niels(
+                [0xb757fa4fc1308db1, 0xbc4e6a21e8ab8d21, 0x91daa200d9306084, 0x60031697070c1256],
+                [0x826c97b5510df5c6, 0x82b6f7115b5e63b9, 0x4a0b14e920975758, 0x5a0803bed851a40d],
+                [0x4cad22b08d0076e4, 0x2615fac4d1071cce, 0x8e0c17c791e954e4, 0x698ff64c05ec8e0f],
+            ),
+            niels(
+                [0x9a254c1a00c56cc7, 0x38703da8bc5f43d4, 0x12316957a10aaa21, 0x63ed4d9c4225919d],
+                [0xf7b31920df519d79, 0x10acea0d13367a95, 0x156d8dc2d592c7fa, 0x6c55c7ede62a844b],
+                [0x339abc7b02d9f06a, 0x2a40ad000b78a279, 0x5a9e1b1df89e3b45, 0x11b7981d13f84b88],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x1e7a00f7a8c9bd8f, 0xd8135c3c49b162a6, 0xee61bacbc0232954, 0x43fc646cf71467a2],
+                [0x3ab5cfd3884a0108, 0x2fcc46fd4f635e2d, 0x4d7011bffab43f78, 0x710639c33341d71a],
+                [0x80aa68af9fbf6f51, 0x194b790092ef6620, 0x51c6947f6675ff99, 0x60064082aea5a1fb],
+            ),
+            niels(
+                [0x50a20936d07b1f1c, 0xac6aaa9895cd6958, 0x92bba7e4a251218a, 0x422db0f77657ed34],
+                [0x62f14822695470a4, 0x7c1d1145fccd5987, 0x20e315cbeae1e41d, 0x1966ecc5be8d734b],
+                [0xd5fb735052c5c7b3, 0x1496ac20bfbd1e3c, 0x9601f51f73f343f6, 0x6d8c5a341416f1bb],
+            ),
+            niels(
+                [0x0e26b52b2092c0da, 0x3503572e826eefa5, 0xc906bc9b6595c89d, 0x64506e64cbe04b9c],
+                [0x343feefc5c4f1c31, 0xac77763fd6e6b7a4, 0xe79098c0a0e8000f, 0x0d9ea8d1cbbb255b],
+                [0x0bf05e2233469ef5, 0x6db5d9f262b140e6, 0xa6cc9663ce89867b, 0x2bd7806ef0f3a73e],
+            ),
+            niels(
+                [0x34b49bc1767c6b62, 0x3f4857125e9b3b74, 0xfdeb4d908e6a8933, 0x1125d7a67fad2e9c],
+                [0x3b512fadc24d6958, 0x7142388ca3317ba3, 0x98aa99014456b077, 0x18945911e20ec522],
+                [0xcd8bc7919775495d, 0xde9dfdbcdcce6253, 0xc58a7ae825d67b27, 0x0e29099daea27cc6],
+            ),
+            niels(
+                [0x23cce453f49ca91e, 0x0d34976c7878a64b, 0x4ac9f1a28906c764, 0x5aaf02e1a133d898],
+                [0x83af52cbb2682f53, 0x96c0066d16b69c7c, 0x6b6205c1bd6ee282, 0x62dbdadf00763892],
+                [0x597a7dec8b2c07c3, 0xb213f0fb048482a3, 0x03cc8b80adf2f11f, 0x0a2a8dee42e34560],
+            ),
+            niels(
+                [0x25cc4338d618ebda, 0x4c5f93c4eaaa4b1a, 0x37f1150e79e66080, 0x13bd51829822f748],
+                [0x92f16ed21078a2d1, 0x800424c7e174eb58, 0x439c211d96308391, 0x4a563a16ee86b4c6],
+                [0xa754f0eb51fb9e22, 0x24f4bc0e8e20c1ac, 0xd091f11bc0fdd317, 0x32b101e92387baf6],
+            ),
+            niels(
+                [0xea7dea7d7579b1fc, 0x408da818481504f7, 0x1574ff023b71f9fd, 0x5e2c0cf44735e68e],
+                [0x1b62e079e02c691b, 0x677f5d68b0f7d08c, 0x02c5ff95e4e1e172, 0x49d4508f93270528],
+                [0xf39e5d46be30da46, 0x78a60a5c0ae02390, 0x83032e372ebdfa57, 0x46a5319e7fa29e30],
+            ),
+            niels(
+                [0xe818319c3d98eb01, 0xb0bfaa3ca34f17e4, 0xb58d7f67d22bf6cc, 0x4129f3bbe04cc3bc],
+                [0xd2061dc6ffbf55a8, 0x851386dbb763c30a, 0x43533d886867c1ad, 0x543c4ce996fef842],
+                [0x873ba02b148bc8f2, 0xae9f496293a86ba6, 0x02a17f93fe4ae28b, 0x17e9eb4ae9a4fd07],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x10af5d5325b9a077, 0xb699465859b8ba56, 0x4343d6c9c0a4d695, 0x1b93440524ea4764],
+                [0x6c8c7373136b01ba, 0x7ca5ab1b9b622023, 0xf3efaf0373e57994, 0x3e635ea84f4ef4ac],
+                [0xb75ea0f915bc1814, 0x980aee3db9c6ffc9, 0x1410946990816132, 0x6b0e8161ec593ad6],
+            ),
+            niels(
+                [0x0860479088542958, 0x2d436db2f48e7e2a, 0xe35c05728135fdbe, 0x14d7c70d0e91f9ba],
+                [0x129e5c680cba4b9d, 0x9713ea3fae1a1449, 0x69e5e0aba631bfa4, 0x361de1b7a28ea18f],
+                [0x294748381b47e5cf, 0xd9c987b3ec328493, 0x2628069d77e0bc59, 0x4af8ab54d2d64a63],
+            ),
+            niels(
+                [0x67f249409f008a7a, 0x07115eef017c31c6, 0x2913d95ef72c9c78, 0x72b6cb01ef3cf9ce],
+                [0x37e6b74e20f4988e, 0xf3dc312ee8027160, 0x01e35cfbaad003cd, 0x59cd6e5530c07b18],
+                [0xca1d45b6c0bebb11, 0xa396c1ded3bc788c, 0x2cf8323119fc075a, 0x3e952eb2d05de8b1],
+            ),
+            niels(
+                [0xb5da9dc0d9e0bb35, 0x864f42652f281ec5, 0x801ea545c51d4ac3, 0x3adf875cfa73d68d],
+                [0x0344cb4ec8011cb9, 0x103eec2d908f62e6, 0xc21cd94539d56018, 0x00dcc07b5d037b68],
+                [0xb5a193e10c5d7a9e, 0x62139f54d12e3aa5, 0x0fc78bfe43060d64, 0x2f6350902d201646],
+            ),
+            niels(
+                [0x49c417acf08f208b, 0x098a7d5a0cecb198, 0xebf85209b307897d, 0x2ec60e1f85b67eea],
+                [0x9c7f094df83fbbb8, 0x896c3036b1699fee, 0x85406bed389b444d, 0x201da1d05f145d55],
+                [0x3df94d3d23a2476c, 0x5aa29b8e86dc7d1b, 0x60c2dcbb1446c76f, 0x5332cd33ef496176],
+            ),
+            niels(
+                [0x2743a0d100ad8a3f, 0x7a2465e8f0c69cae, 0x00f1d33f611673df, 0x372b04a6d782fcec],
+                [0xffc8841ad55141af, 0xfd04dcfb93127e4f, 0xee9b699a3b7af7ba, 0x0a45032666eefc1a],
+                [0x7951eeadb077e3ba, 0x531fb06cf587ad81, 0x50fd12dae17fdb8c, 0x301a83e465028be5],
+            ),
+            niels(
+                [0x57b59b6366c93723, 0xa13408f47d435a0e, 0x4784b9eef8ae3059, 0x1773403ba94e843d],
+                [0x39ea022b8ecf061a, 0xb0270df08582f2cf, 0xcf1a8aa36bf90f71, 0x16ce12ade54bf3c1],
+                [0x1543280276459b8f, 0xd40d7682648ed830, 0x5f7a3eb834c62479, 0x1bf2ad2610b77a48],
+            ),
+            niels(
+                [0xaf8e6a39ceee35e5, 0xe5f1d18f7646c6db, 0xdd50dde1c10009f3, 0x2d787cf64aa2d1de],
+                [0xdf8dd4b0f2f99c1d, 0xe3e0edbcd7cb7226, 0x0e64587cc3778c28, 0x25100406982ea872],
+                [0xcea7dca471e838d2, 0x2d651413316c01ce, 0x4bb85894a3284238, 0x1c932b31ca3fbec4],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x82dab61959422e3a, 0x6f2dcab4b560ea7f, 0xb03041cfa7c769fb, 0x320184652d562298],
+                [0x12bbd8866398f0c7, 0x2ee9fdf5d2439740, 0x918570dbf2f7147a, 0x6e20f11678577cf3],
+                [0x030cf6d00d236bcc, 0x750148a02e93e261, 0xf77e3d09ccef3e56, 0x09c70ad8cf43bacd],
+            ),
+            niels(
+                [0x48b0e5d6034d28ed, 0xb7dd5a3fc7e7c8a0, 0x98d7c5ae9a9dd6e2, 0x5f8123271e05f81f],
+                [0xc556d7ac0f6f5abb, 0xe8baee1c522e3882, 0xa9dd32b3e4fb4138, 0x6160624912326ee2],
+                [0xc8257c0b2c862065, 0x12d78aca2605972d, 0x524b491006afc71d, 0x73964771978b03b0],
+            ),
+            niels(
+                [0x19597f12768ade53, 0xd748704dae023111, 0xd6dfec5efa6cf183, 0x70cdf355c38e949a],
+                [0x0dc757e5ce1f257c, 0x1bb7c63653a2e0d6, 0x5921b6d60f126660, 0x408b9a5567b00e03],
+                [0x4fe3378a7d5465d7, 0x3937220fe2a4d6f6, 0xe6111a83073ffeda, 0x4fc80e08fc14265e],
+            ),
+            niels(
+                [0xf09f32fea51fe4e4, 0x03b4ff55d6614a1a, 0x05059d7ebb2784ed, 0x5aac0c9879080b16],
+                [0x5b4a1a4c01ed7ea7, 0x41b42744c9b83561, 0x30d3da1a5b0d1f83, 0x3daed50c6ad3e3ed],
+                [0xdebbdf2f27809160, 0xa5c0da060b23e099, 0xf6884710e3d90bef, 0x3d78b7fc116651a8],
+            ),
+            niels(
+                [0xe59f06265f4f0d22, 0xd85e888acb29dad3, 0x87892f10457e2369, 0x1a758c8413faf7ea],
+                [0x1c33fcec5c1d6da8, 0xafb50eb76678794e, 0x14c6d4bdd051cab8, 0x5c73d978a0149f0e],
+                [0x0aabebbfa3860ac9, 0x93759cbdd995051f, 0xa0dfa78f901424c5, 0x2088cf84dd9d5ca1],
+            ),
+            niels(
+                [0x0128a05fdffba95a, 0x955e92bbafaa3961, 0x23660deb1bcf05a0, 0x0e563b5f89cf993e],
+                [0xcc4ecefaa192ac00, 0xf8da64955631aa15, 0x6b8a8f1828fecf0c, 0x0ccd80eaeeebfc7e],
+                [0xab8e94660999589d, 0x664abb2ecf017fad, 0x01ee8f1db2937c4f, 0x4c8a8b9b73a06203],
+            ),
+            niels(
+                [0x13a4210a904c7fd3, 0xf5a4982f12356103, 0x9e5075cae887293b, 0x0892e31945473c5b],
+                [0x890b6f56d9dc0c46, 0x8cb4a7566102bc1f, 0x9e3420aca8bd0e97, 0x57dd2ac71757b77a],
+                [0x02cf3ccf1f67ea49, 0xabf9f45b65114bce, 0xc8b776bbb3afa693, 0x4ee7939c463ddd5b],
+            ),
+            niels(
+                [0x896c332663fdbf5a, 0xf40e25e8c2a7f992, 0x58293a4b1d9d5fc9, 0x37bcb4f5306ab5e0],
+                [0x29c5cc6c7eb1bcf5, 0x9762c59e5d537aa3, 0x1ad1bf08ab9ff95e, 0x2d978ea1e9b3922b],
+                [0x464984aabe5a6e49, 0x54f6a09c4029cc49, 0x0c4813586175a5a1, 0x46b61c98184fd5be],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x01e7d0cf20151e48, 0x6359076b3e28d737, 0x0905e6ff80bdd71f, 0x1d7366caa9eec44e],
+                [0x8bb2ac4c3323e85b, 0x5b0ce3b92889156c, 0xd64c935d6f1f16a6, 0x6dca96b2c724ad7c],
+                [0x4b055e42bcab352e, 0xbe24d9b18f4b93bd, 0x07b34ed523ebd3e5, 0x5d77c2a0b1ff3240],
+            ),
+            niels(
+                [0x92b7e2be1ab8900a, 0x2d6a03e120d5ff90, 0x85f826b4be1afb30, 0x42bee798409589fb],
+                [0x9cbb7f5aa641bd5c, 0xcdbc0e38611be770, 0x05aea3e5231dbefc, 0x6df4030ef2899a6b],
+                [0x69dc15f70149eee1, 0x8436faed3cfb4b4d, 0xda8b01784bb121d5, 0x2b73885ad5b7be11],
+            ),
+            niels(
+                [0x8522eab78e43bb7b, 0xd250c8f21d06a5ab, 0x94553a1d4025b82f, 0x28706bb3ebd80683],
+                [0x8e8d4ec62edae215, 0xf9502b50f5b3f658, 0xd5dba7d52650ce6d, 0x45d82bb59b6d2cf3],
+                [0xaad4fac79dc6b55a, 0x6e058a8d0f6bba6d, 0xdfd99ca59e72bca2, 0x6479bafa24acc69b],
+            ),
+            niels(
+                [0x531b4745efb34206, 0x56d6c1daec574b88, 0x953889f04016e368, 0x607c6b872279f0f3],
+                [0x3ec7ec0b2d6b06e9, 0x09c053a30b259d8f, 0xd597af0a8d57703c, 0x272be51b929304fb],
+                [0x946f7bf2e0bf2d24, 0x9c46e5ccf051f597, 0xe0cf7d04bf0235d1, 0x5436a948e1d2e5b5],
+            ),
+            niels(
+                [0x44bd6f2d4703c49b, 0x5c6bfa3834863696, 0x99abe6b23779961e, 0x3c0549d1fb57b82c],
+                [0x66217145f55505d7, 0x35bd1ed81873fbb5, 0xdaaf5b50a9bccec6, 0x02a5e4f7f4928fc7],
+                [0xd38b9eba63595754, 0x549635960c20e2b6, 0xf796e2e914d25baf, 0x42688b842a5c6e19],
+            ),
+            niels(
+                [0xe460453a1574868d, 0xc7b8883495ca5a4f, 0x77d12f036c10bc7a, 0x606d4c210d0588f1],
+                [0x2d677383e86021ff, 0x61bfa6c9ffb1617e, 0x193bb94cdd00ebc0, 0x08249ae09e4dae8e],
+                [0xca10895765469ffe, 0x2c9d43546b897f68, 0xe1abedec8479cad4, 0x4e6687fe6c428771],
+            ),
+            niels(
+                [0x930f4dbc3d6b0ecf, 0xea025200fdfd0943, 0x041ab292fcf2d4b4, 0x6fb4ca904ac05548],
+                [0xc1d609256100d25b, 0xf6d2ce692a8f71f6, 0x580d7b0598cbe9cd, 0x344a32dc05682b0c],
+                [0xbaa363052d310f2a, 0xf883f559e318fe82, 0x20e423363c80efdb, 0x3dd313b8b17b46d4],
+            ),
+            niels(
+                [0x724cb8d1214176a6, 0x1d91c2f7b49987f2, 0x5ba10be91f024aba, 0x6026fbef26a6adb4],
+                [0x8c5e42eb58a2d046, 0x5f221622fd9e001a, 0xd1a8c1c27c793ee2, 0x38c140c4b7b01897],
+                [0x6fef9a0c77adaa07, 0x4d9e426a3f5be891, 0x063dc0eab4377bd6, 0x27a88182f6b27d29],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x26ce45136f28285a, 0x83b4a4e2e45879a0, 0xba63f1de4ac30042, 0x17a02ee7a6c834d2],
+                [0xcea1fcb9a057e533, 0x46cf78896fcf8d85, 0xe0f8af50ee8ea537, 0x50ac03b78e478e5b],
+                [0x075968bbd35b66b8, 0xdefc5ff74067de0c, 0xc795715ca3e5a1d0, 0x0f8708fbc003730b],
+            ),
+            niels(
+                [0x2e007a2a431154a1, 0x19f2695fecd18b39, 0x3c28e6f60d0850ed, 0x1073cf356ff761be],
+                [0x3d5ef8235991efe9, 0xc2af3587a571905f, 0xfab32ec99a77a072, 0x134854eb8c0118ef],
+                [0x56123a9ae6c532c0, 0xc0feac8d558b205c, 0x58620e7d0ddc7490, 0x343d8dfa34a4b775],
+            ),
+            niels(
+                [0xb94faeacc3f05458, 0x179130356e54e3d3, 0xeb7b5d49e6a0ec3b, 0x33f21df8bbf05133],
+                [0x952856e2250962a5, 0x261457a2973484c5, 0x43a992db954efd12, 0x3e2b1ea6b33ff1b2],
+                [0x11f1d35c18a90788, 0x0f749d2beabc566a, 0xb4342dd60143a86e, 0x4fb152623a75e7da],
+            ),
+            niels(
+                [0x9dff443c9acecf2e, 0x8fcb1b9cc52438ef, 0xf5712c2375e498ec, 0x26cf5759aaf5eb51],
+                [0x37a193e4b3b6801c, 0xb468c7880be923a9, 0x716895316f251160, 0x001e7942b2a420b2],
+                [0xb3e2b3e4dbb9e01c, 0x64a62efc9d7e4476, 0x3cdc4bb687c7586b, 0x286dbce54fe8cb55],
+            ),
+            niels(
+                [0x7a959a9594cd31a1, 0xd00143ba259da056, 0xf51b71ac0628a4c1, 0x300e26135501593d],
+                [0x4d0ad055e72bbf95, 0x42962ed0120870f3, 0xe986af602e3c139b, 0x4d2f904cb49a5434],
+                [0x5ce5c291fd1299dd, 0x152d0ef9c8039331, 0x46dba12ad23d5ec4, 0x11d007dd619f4751],
+            ),
+            niels(
+                [0x26bf93ae731ef98f, 0x7b0895fb35ef2b18, 0x51f1d547b1960259, 0x357257c72f43d6e3],
+                [0x6159aea8437bb7cb, 0x1b437d1324396eb4, 0x3073003aaca4c9cf, 0x45ee68b28457acb7],
+                [0xb0d0497e1f571eb6, 0x25f3c63b922e1349, 0x1c08ff8e573deb36, 0x6288e36787c9c522],
+            ),
+            niels(
+                [0xc6526553b8b8006c, 0x7d28192b02f3a641, 0xe99bbe288ce0dcf0, 0x6918e8108e0ad7ac],
+                [0xa2d02bfdc29dfddc, 0x59100d54566d1c2a, 0xa2cb050ecc29a1d8, 0x307854d19bac4262],
+                [0x58fb76ad5dd24f18, 0x25abb83f4d82ceee, 0x9d2f682d92159371, 0x0d67801d2dc03219],
+            ),
+            niels(
+                [0x57ce63c216b0ce19, 0x39a71117edc88879, 0xe4b615bd8611a785, 0x6bd21d5ae58f415d],
+                [0x6cd51eab8d2245af, 0x6cea571f4d1a9c50, 0x867a11634a9d5972, 0x578e84d9bc1e71ee],
+                [0xd3ccd8167d193e78, 0x8e6e3eb036f6faf1, 0x01659f142adb1c96, 0x535b1a598f0ff373],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x7b46edd25bf65f7d, 0x5f4fd1ba36276d82, 0x5eee3ee96a3c4944, 0x020f907fc4bc5272],
+                [0xd71825852d74f7f6, 0x527be3fac4fb275a, 0x0b5e10e98b964dca, 0x4d4da6fcd2b1be27],
+                [0x0963436e8646a289, 0xcfeb092eecc5edf3, 0xe82cd5c38cf27089, 0x1420c13281d71b32],
+            ),
+            niels(
+                [0x7109f6650ac998f3, 0xf5e6b00a75ef7b44, 0x2cade0b865a04a7c, 0x19fc19d117ceaf58],
+                [0x6ede1ae76e26e6b2, 0x4fd1901f793597a6, 0x48f8a06020b7ea17, 0x1bba79d95ad7015e],
+                [0xd96c1efa0be9acc7, 0xb096c2d91de7848a, 0x2c3a2fe43c93f783, 0x1d117dad0f4c7d2a],
+            ),
+            niels(
+                [0xc656f4d124c20f9d, 0xd20bbea70ac13b78, 0xcea1780ac62b2865, 0x579f89cb395e90f0],
+                [0x778c3f346cc64655, 0x3b05da914781d022, 0x6aa531730bc41019, 0x6808ecb321357f7b],
+                [0x996c58108ac6769b, 0x2f273c2630c13cee, 0x1d6f10c6adb55b75, 0x6996e2c81dd47ccb],
+            ),
+            niels(
+                [0x5a060afa8ff5b2b4, 0x32810c8551a2a838, 0x2be2e921be4b78f2, 0x287941502c71521d],
+                [0x36df76710f00a309, 0x5906be3cbcea246c, 0xb715dcf8694a0163, 0x44744c928cbc1e1f],
+                [0x0c13de4288856ba8, 0x671fa9dad2138147, 0x464a37b2d365c913, 0x4ec73b7fc7cafce8],
+            ),
+            niels(
+                [0xa6caa7b54350f8f5, 0xfea226498f1693e6, 0x51e8390e841bcb32, 0x1ad3fb29864b5a76],
+                [0x4188865ac460f3aa, 0x6f5ac9073d3821a6, 0xe38faf31c0b7f943, 0x3d99b2e032229c5c],
+                [0xbcc119f8f6cdd0c1, 0x2834b0c46c2fe4d4, 0xb02e2b945cf53083, 0x04f5e10391f01562],
+            ),
+            niels(
+                [0x2d7fd4b93f203d8e, 0xa0b15855870d883a, 0xe017ff12f1283c91, 0x49315e12301ffcbc],
+                [0x3d07af892b3e7bf9, 0xd5e5122e6817bdca, 0xc2f48fcfbbcc306c, 0x6141247158916042],
+                [0xc76ca114aabf978e, 0x4857081da301acec, 0xf14e120484a07235, 0x292063f494e08963],
+            ),
+            niels(
+                [0x684a41a6e319ac49, 0x4de637eea0e01efa, 0x4435a313cd3acba6, 0x37d3f7ff12bf18be],
+                [0x357dc1d5213818bf, 0x571f952e56daba07, 0xb052d18f8e88f995, 0x55237642261330cc],
+                [0xc2b7c3c39f3a96b6, 0x5e45220e4cbcccb5, 0x5b0b5d97023e6f71, 0x2e4d63d59e238e6c],
+            ),
+            niels(
+                [0x6899daa2e64c5109, 0x30aefa183681b87f, 0xfd618479548750c8, 0x70ca23d838108dca],
+                [0xb88e626007287d8a, 0x3ea8f089ebbfb044, 0xeaa6d7e58af79654, 0x50a7d716bcb1eedb],
+                [0x7eee58dbf95d9745, 0x16c91f1ff8c4e881, 0xeda8d7f0d341713d, 0x55ecf7bfa456fa30],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0xf60a7aa94601ded4, 0x478464b3424d8bee, 0xa1753594f8ee1c95, 0x3a754ea7fba09a68],
+                [0x6eb1a86f58b4ec8c, 0xec5de584b5a9e33d, 0x52bb912c69461879, 0x552ee737ebd6863b],
+                [0x02566073c11ec76e, 0x68b3826806e341a6, 0xede213a48c512cd1, 0x66f2fd53afcf450b],
+            ),
+            niels(
+                [0x74aff20ab273b437, 0x1cf656502b0599ee, 0xe360136d9c926a0e, 0x5093aa3cb9cb5fa5],
+                [0x96e91b0079f071e3, 0x76dd0fa2602f8bac, 0x071433949ce56c52, 0x141db1bcafe88d9e],
+                [0xf5c708d0763df7b3, 0x4f6a91ece464c0a3, 0x160c0e250330eacd, 0x61d2ca0f1b9ee444],
+            ),
+            niels(
+                [0x9286fa9cae7a85b9, 0xe9bfd8a7291441a0, 0xdd159c40b80a47e6, 0x1f1f153178dd867f],
+                [0x71da18cc7a1b7050, 0x73f857b878b5faa8, 0x9933b034a723d2c7, 0x19a13f9e15ad3861],
+                [0x046612b0871e0b8b, 0x068afaea9540f7e0, 0x8989df97c4db6138, 0x3559196c76ea2799],
+            ),
+            niels(
+                [0x080a010a5eb0566c, 0xa0a63a501fb1734c, 0x99d81d0230610838, 0x631aa75affc59ee4],
+                [0xf4e2ef4978a2a1ae, 0x21dc74b8013f11ba, 0x2bcdd122adbad984, 0x3ac4a9db9f1bbbe4],
+                [0xb08599b6b289c59f, 0x5569461a575bee1c, 0x3d9125648440f6ea, 0x58e28550f99a4a73],
+            ),
+            niels(
+                [0x30ab84ffb8c0af74, 0xc1a9c6d1bb329a9e, 0xa9f433ac6be0c371, 0x1b59845757e14d28],
+                [0x3e1236643f7f8647, 0x3666ccb5a3992633, 0x2e71912006c29a54, 0x070e37d1cce760c1],
+                [0x2533355e0006f424, 0xeac19cfa9fd4688a, 0x41bfeba78fb68d59, 0x40ec948aaa40ee59],
+            ),
+            niels(
+                [0xa494d5d97817a8b0, 0x30a069f9e7fdefb7, 0x101ed2415f0c7379, 0x1427ae6b96f420f9],
+                [0x5bebcee866fb0760, 0xbaae86be4a8b30f2, 0x19531d93ceeb1d3d, 0x0258df231c06c5d0],
+                [0xfcd662da3ec88970, 0x500ca37d97bd3041, 0x4fb685a22c3baa29, 0x32ec5d8b96b9b46b],
+            ),
+            niels(
+                [0x0420680b69f2ebd6, 0x250a61bda1a4dc2a, 0x6d2cabe796e7fb64, 0x2cfa2ccf75e1d829],
+                [0xeca604d294ec9afb, 0xd186600c661b2435, 0x211af1ada95470cd, 0x637c5cc11c4f0484],
+                [0xb2a4e359bc1277a5, 0x62f85f024a762db3, 0x7586653005e8a135, 0x16f4b77e46483803],
+            ),
+            niels(
+                [0xe0c782560c845e42, 0x69c8817a8f27d3b4, 0x81085614834dc501, 0x1796eea7951316c9],
+                [0xef78a58a9b781e22, 0x14100f27b4f27e8d, 0x6c9f9bad02bea999, 0x6bbc15ac61f043f6],
+                [0x662980f4158b865e, 0x851b3c081637a89c, 0xb88a184bb5e78fc3, 0x42e27c1248b17c83],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0x2b660ae2999f9659, 0x6a275447427a85f3, 0x82c7e5be4a1a382a, 0x5bfde933dcd0538a],
+                [0x5166e673a2655e87, 0xefc2a8f2c1f1f042, 0x36014aa52f7fdae1, 0x47c872d2ab9c08f3],
+                [0xd7a40ce8d2b459df, 0x228420ff4e8b7ae8, 0x46b61a3a3b71574d, 0x16ec983cae1fa1fa],
+            ),
+            niels(
+                [0x8b1dd4e0f13c3f5e, 0x776ee6cc11926bef, 0x728891c6db81ca76, 0x11d4ebf6846dcf7e],
+                [0xddff9a1b6fe3e9e9, 0xe1f288f4da6dfc51, 0x90b7ee5d84e72abf, 0x19be458376fabcd4],
+                [0xf580268e651d4026, 0xccdf4456de62c028, 0xc8391c59c7c0a74d, 0x111bb00e301d5670],
+            ),
+            niels(
+                [0xd8c0a5c4556c9f74, 0x0e88a51f34099c48, 0xf95eb60efbf6954e, 0x0ccde2bf1e9eadd4],
+                [0x1cfbeb063af83e56, 0x4d26c85e6a90c858, 0xc02b10e630f08100, 0x33bd8b80fe1d6048],
+                [0xb59d3e599f2ec52c, 0x38c03c0ea6b8318f, 0x2c23c5f0ee41beb1, 0x513d544688e231cc],
+            ),
+            niels(
+                [0xc8c56d473baccf80, 0x3d9b041fd59383bf, 0x36137993c175e025, 0x244fa87deb1e4b78],
+                [0x0be14818663f9cc8, 0x8d9cdaf6051b12d6, 0xa61e517a156d3907, 0x251814aab1b89604],
+                [0x3e2e14e000b1ab9f, 0x3a18eeaa1e02f7c5, 0xe7a3bb67e6a553e0, 0x1ae54b7cda68819c],
+            ),
+            niels(
+                [0xe784239b3e078773, 0xa85c6bfe0773b0d5, 0xc339454831ca313f, 0x2bf844dd9e94de7c],
+                [0xd5505e5450beaf9c, 0xa39b3e7f05e89fcd, 0x57cbe9e5fbe53fc7, 0x484eff49aa6fd465],
+                [0x4812d0ac1522928e, 0x7b12df3061e932e0, 0x32243349f845dc28, 0x635aaf82b8c7dea5],
+            ),
+            niels(
+                [0x019dcc63299db2d0, 0x48b4f80f2dae7fbd, 0x1b0fbbc56eed03aa, 0x4a9a3ff45db0c388],
+                [0x5429f8f8e68881d0, 0xa30609846cbadf8c, 0x3a82bfa09257476f, 0x658384520dafec60],
+                [0xbbf9e1f54311cf31, 0xd0756c72cc3db3cf, 0x4ac0595c5e7a8d48, 0x2ffc1936b169e2a6],
+            ),
+            niels(
+                [0x675057958b778642, 0xd2e3528caf279752, 0x6b2aa1ca6bcd471a, 0x54d59af0993b5f5e],
+                [0xaeca2676f3210bea, 0xbeb60aa3f1ad7d39, 0x96cc342d35b728cb, 0x64cbf2a64af24cb5],
+                [0x1a56764d2f04f74b, 0x42b62999ce86de45, 0xaa9de878ccd83918, 0x3804d5ff00c3e742],
+            ),
+            niels(
+                [0xaeed1bd85541b515, 0x66f73f33b567c0d1, 0x7d3346e66811b133, 0x4d9065cf5378d642],
+                [0xe36cf5aaca406709, 0x6820a70e0e8fdb99, 0x1ae22ef94ffd2e2d, 0x646aca3e49811157],
+                [0x760beddccea926e1, 0x212865b3d53d599d, 0x3af9ea1716c7c89b, 0x6303a6fccf98b43d],
+            ),
+        ]),
+        WindowTable([
+            niels(
+                [0xe7c51a8484e9c8a1, 0x121ce0373a1d25c3, 0xccc29dbf2a73b518, 0x101a9bc3d9db5fc5],
+                [0x63237a835ee45588, 0xee8f8a049b0d88a2, 0xd8bd05dca7ca1b70, 0x34a6c8a402946959],
+                [0xf6540976a12ecc90, 0x419b6610e4d4dfd4, 0x40724e3123c6f2cd, 0x73e98253b7bc5371],
+            ),
+            niels(
+                [0xbb006b89677fcc50, 0x803d3f6eea9b8020, 0xcb1ad11d2fd3df83, 0x379735eac1a973f9],
+                [0xc525e5003149dac7, 0xad35bbecd17ab87e, 0xdef86ffa88e5872a, 0x450b7ec6f93c259d],
+                [0xaf9f5538c32e1ec6, 0x084c0ee406789573, 0xf3a55ef78dc0d32a, 0x1b7b0b98cacdde18],
+            ),
+            niels(
+                [0xab7e74b329a3a3a4, 0x4be26b791cdf9e22, 0xbebf77907597e842, 0x59a8ee8f173ef67e],
+                [0xb98d497395789780, 0x1e7f16e19cdf03ee, 0x3fba05565fe8233c, 0x54ac26252af8a8b6],
+                [0x9b084d0027cb2b2a, 0x3790a757e29b82b3, 0xca27a4e17ad5793d, 0x470a6d054b5c6e52],
+            ),
+            niels(
+                [0xf6c4f10fe3bd091c, 0x3cb006665e4c26d8, 0x0d6aa2c2aac8e749, 0x0b2907d3d15debea],
+                [0x902510277f769993, 0xbaa8bf92fd887636, 0x7bbedf6c6eceba82, 0x4b9366ef733ab68f],
+                [0xa49d367d1ed44a93, 0xd2d513c6fb71fbfb, 0x10301be0e2ccb1d8, 0x2fdaa41bb4410743],
+            ),
+            niels(
+                [0x212c34cce8282a7c, 0x955f26c9394d5556, 0xd8b5c61aee9f1590, 0x0470deb2a0dae80c],
+                [0x59af652e3872f012, 0x05a48885f4cb7100, 0xf830033176c179ff, 0x55c9d2ef012e3ea5],
+                [0x724bb183af738a4d, 0x7da729a56c146d11, 0x3be178dbe427ddf0, 0x55e4f909eeb23dcd],
+            ),
+            niels(
+                [0x31c7285841415887, 0x2ef7ce1b0dd9a184, 0xc043119209874e3c, 0x08279a5685ef5d44],
+                [0xdecfa64ab9630d6d, 0xefa9e14c768982f7, 0xd5b6aca6cf430a5b, 0x31a732b0b310d127],
+                [0x47b74f30e07b82c4, 0x795b99e9fb6f677c, 0x6f6296f45a42d968, 0x1798e83ec626a30a],
+            ),
+            niels(
+                [0xcaa3a8a64cab0498, 0xdf567cd497307488, 0x37ffb4f1ff24d706, 0x4bfe477cd31f9b74],
+                [0x8ce389c7ebda349e, 0xa877622b100dbd61, 0xe3c5a162151097aa, 0x6be1a9ae04218294],
+                [0x509870efbd45c8af, 0xc5fd34228830e7ed, 0xad8bbcf1acf4bc50, 0x22dcb83d9488937f],
+            ),
+            niels(
+                [0x00aa9c97a6201586, 0x5820e33653bf29e4, 0x22216ee898de7068, 0x15ec8e86a557ec5c],
+                [0xf6bea5867ee326c7, 0x0d586ea6eef18340, 0x054114378531fa3a, 0x6e3a4e326c35f186],
+                [0xb3ad38f2cbf73741, 0x7c5425293fdd7b74, 0xad47e38ba311e197, 0x539b02412ab36519],
+            ),
+        ]),
+    ],
+    // [G, 3G, 5G, ..., 127G]
+    odd_multiples: NafLookupTable8([
+        niels(
+            [0x56fee1e3ddca9bd3, 0xd0b39369d0ad3e25, 0x5e7f6b176b4716bd, 0x3f8a04a1c3f59ecd],
+            [0x0a697c878ed880df, 0xe289102a084823ef, 0x10b0e51dd0de2d60, 0x21d434dffb0b1837],
+            [0x7248150cab48d0b6, 0x7c8e586f93b30f3d, 0x1ec8a19f5f644691, 0x32e93209868bb8a5],
+        ),
+        niels(
+            [0x81e782b5b07816de, 0x408d6963350131a1, 0xfa131201b7dc6d58, 0x257f0c99ad82ecba],
+            [0xde864edb821932a9, 0x6d07839fe9284806, 0x5bf9d490c1c4fbf2, 0x0d5ad281993b3ab2],
+            [0xe1e7530e73e655ac, 0x5029c56e571efd7d, 0xf05624b0d498055a, 0x0ba1b9bdbf01f8a9],
+        ),
+        niels(
+            [0xbc1430355cc5f758, 0x4a89eaf806e8655a, 0xf21e0dc4abe3253e, 0x3fc52ff48015f36a],
+            [0xae52acd4fa458e3a, 0x4b073db9a6c6486b, 0x32de5d2497b6263b, 0x39eea2099073c9be],
+            [0x15eaaee8c8751df9, 0x01d153e98da626b0, 0x662b25c56067edf8, 0x4b15e990b73a3f56],
+        ),
+        niels(
+            [0x6cf4e8dd025f3be9, 0xca7730e1b3a9fb26, 0x38a2bbeb43db27d4, 0x180282f9674623b7],
+            [0x7cea9049e796d023, 0x9a94e6e031d92cfe, 0x5337e35cbf084230, 0x0fcc9fc83966553b],
+            [0xf85605b4f987d741, 0xf9b0dca6a48fbc25, 0x0f3d73883ec09e20, 0x3a569e6b0653e0fc],
+        ),
+        niels(
+            [0x13edefb653dabb0d, 0xa5d8339535253c69, 0x4050d0a6960a7798, 0x5c23b6ce5b55b1b7],
+            [0x5f332d696bbd3fcb, 0xeca8a3271632ab6b, 0xdc53b2457ca1a85a, 0x0c344c251adf396f],
+            [0xf714f326c05d60d9, 0x753beecc7d97142b, 0xea313cf6eab8458e, 0x5fd497c80b15116c],
+        ),
+        niels(
+            [0x888a8accce061d3c, 0x99ac55c834751fc7, 0x1be89010c93d23d2, 0x06d13f36bdce2d13],
+            [0xc7981fd2fca7aff6, 0x59eb5bc207794939, 0x915242d565450dc0, 0x2fd5991cbd48bb3d],
+            [0x17f4c58da3efa17e, 0xae94267f5aa9d8c1, 0x20e752077d4d7ec6, 0x10dd79c5e4997535],
+        ),
+        niels(
+            [0xd10d845905654bc3, 0x3351d6c5de0a2aec, 0x01478b66749c8f08, 0x664525c05f65218e],
+            [0xf3c608d7b8e5c808, 0x2553393b2fb7f752, 0xfb3f10f954aedfbc, 0x0cdf8f8bbd7ab179],
+            [0xc6f96b29d5a87c78, 0x41022e0caf11a0af, 0xd7c19899255729af, 0x5b37b114b51579a2],
+        ),
+        niels(
+            [0x3da8390aaf610591, 0x9932973438806964, 0xbe9fbcd342e58468, 0x40919b1e3e88924d],
+            [0xd146f93ccbe3f8ba, 0x09da64fb785699be, 0x72814f2e119f64da, 0x35e184063aa9090c],
+            [0xcbd2c90d2bed5e36, 0x4c70c1f85f29ec78, 0xd8d9a2c53c83afcf, 0x064ef41b79e80690],
+        ),
+        niels(
+            [0x679fd52d2b55d77b, 0xa78841a275793bba, 0x9828389943f5a6c5, 0x6ebe6625d2bcfdec],
+            [0x909e8f6c39afb244, 0xf07407afa1072795, 0x72842b9e994c7f5f, 0x05607150d0f2d6db],
+            [0x57a27881a35e0c33, 0x907c5b494179011e, 0x8a4fadda023111a2, 0x172a37da1cb03f7d],
+        ),
+        niels(
+            [0xc3060fe154ae798b, 0x746a62c0ee061f0a, 0xdc9b19e3b014d018, 0x0594ebbebf52b069],
+            [0xca0e33affccff3cb, 0xe0023f31844af2a5, 0xd59ca06a3c65a8ce, 0x29f067ca924df99c],
+            [0x4fb89164d0de8054, 0xf11721a7a1dbc532, 0x5e1364baf026a4a7, 0x73afdff3e6ba08f1],
+        ),
+        niels(
+            [0x894d2dfc99bed7d4, 0x1b11b200942950c8, 0x0e8b8e5399105a3f, 0x130943bb58e0fbb2],
+            [0xb08abda509f8c35a, 0x067e6f9f674098fc, 0x8d6036addfcf0dcf, 0x17ee56d188e513ee],
+            [0xa2b0a4ecf5e959be, 0x99bdfa2648508fcc, 0xda81e6fdbe6d3397, 0x2ca278d702da9fc5],
+        ),
+        niels(
+            [0xf4294fe0b729237d, 0xb5f3a6f560a395b5, 0x7da169fba8e72f4f, 0x36dfc33d191cc7cd],
+            [0xd6e10a33eed2bb81, 0x60bcabf0e026c19a, 0xb8df5971cbf44911, 0x03d2d9866b5d1d9e],
+            [0x28b3ac097df55083, 0x8f4a34130f19039a, 0x7fa3968ea7c37e14, 0x283ebb1ce307d480],
+        ),
+        niels(
+            [0x07cc876887f1e748, 0x25531b2bd1cbb6a2, 0xc81358100af28916, 0x00fc6bb8fcd7d84a],
+            [0x0d9d3e84974b38de, 0xe4dda41cfb1d3d99, 0xf46e989cb3e1a5d9, 0x1fbcd1e39fa97710],
+            [0x399267f214703151, 0x688693355c93f119, 0x198f276970cc7eac, 0x2e992f689464b6c3],
+        ),
+        niels(
+            [0xee623fdd6ee18053, 0xe234dbb2d26df0df, 0x8793d50dde9d2492, 0x3f6696feeeaf6b20],
+            [0x22451435ef289e54, 0xb2e9024b2f532498, 0x3913a8740d1dc241, 0x230b948d6bd4987a],
+            [0x5a5f952f577f44a9, 0x4002135f05c78017, 0x42eb25e19a41dc70, 0x721fd89e7fcfe41d],
+        ),
+        niels(
+            [0xf322de743aa90bce, 0x5174c3e20e501b0a, 0x1266fc282d5b4661, 0x22f669ebb9e5f354],
+            [0x748a8dc5197a1bda, 0x3ca4315ddf02a779, 0x85e5deb60171037d, 0x479ed94a3a77ec81],
+            [0xeb6b5d64f5aa73ba, 0xb261ae93158abbff, 0x99c250bb57a1ee0e, 0x29d2d2a4d4d24c1c],
+        ),
+        niels(
+            [0x005ec93cfcba382a, 0x60b2f8a84b825ce7, 0xb33e687899f066f4, 0x3e6168430aa71419],
+            [0x169a6eeff31380d7, 0x2a4d1a2301665e35, 0xa9caa580f9cfa171, 0x036b6aab58ec7517],
+            [0x05b389e7b861e27e, 0x9173cd362e099fb9, 0xebf4c14ebfd607fb, 0x20df56136d8d3922],
+        ),
+        niels(
+            [0xa91efe0a2a3d5179, 0xfbab31df867edcb9, 0x4b1d7c0734af51bf, 0x466aeca9982772e0],
+            [0xe3d2d8d6684a532b, 0x637b91041a2d1d67, 0xcaf6e11c8d3cd3cc, 0x6921cf53de5e0017],
+            [0xd7aa14b25370c823, 0xe0288e25143c344a, 0x5d1e043e281c4ee5, 0x5f25b85525e42b06],
+        ),
+        niels(
+            [0xbd8590fd4e492839, 0xa2fdd07fe26ef9d7, 0x466861f401674a23, 0x6953a471ece2f81e],
+            [0xb7ef9f871afe81af, 0xc9c7be462a37e316, 0x91a97b1e6b8702b2, 0x17f027d4aa1c3a30],
+            [0x8626bbf5415fc566, 0x6314c18ea5c9d2ad, 0xd87909d87053f744, 0x0d39aa2eb1064035],
+        ),
+        niels(
+            [0xb9b4b838fea8fc03, 0x4eb483c46fc2c1c2, 0xb07b294223268227, 0x19cb62d5cef76d6a],
+            [0x8e260c7a76e2e954, 0x06efbd75357c6c7e, 0x4d30861f90c57d0b, 0x2d619bbb718f7a20],
+            [0x1779517be47e8e56, 0xe2e1f9018766ad92, 0x89acc872e1131590, 0x2156c6df185fc8e1],
+        ),
+        niels(
+            [0x046c9b4dbf6de83d, 0x2a77f7f6485ecfa1, 0xbb4fcc5e8b64cf18, 0x0b3a7c9ad4b4a74a],
+            [0x734e7964d00398bd, 0xb6576b60411ef005, 0x9d8e6f0df79c33ea, 0x554ceee0fce03a5b],
+            [0x14e82c7015dec32a, 0xce1b3a0e7aab86d2, 0x5c84697a675e7dc2, 0x1777c4f19ec50433],
+        ),
+        niels(
+            [0x749c94f286333412, 0x138a8ca414329e78, 0x461cb277d07cddec, 0x393822766ddc4a06],
+            [0xc1143be092f2eb6e, 0x1ca9a4fa66113c5c, 0x8d8c0db65d1d4a76, 0x5a37e1bb2fd72dac],
+            [0xa32127582d7207cd, 0xe30b258b7960840e, 0xfab38772cd1c17c5, 0x6b12a79134666c6c],
+        ),
+        niels(
+            [0x0f96720694d34e99, 0xe0d2f48abad34e27, 0x75782da92bfa0e75, 0x10fea6366f0cfec1],
+            [0x1605a6cb9f198efb, 0xc96aecf1f1628827, 0xd50d9a52f30f97c9, 0x674f884c04164976],
+            [0x1928ac02e1b43347, 0x0c401f512e32a544, 0x560e47314c40682d, 0x29369f7e80e3d072],
+        ),
+        niels(
+            [0xac278f4325ce2a5f, 0xff09b920b1aa32dd, 0xa1483218cacf5e2b, 0x258702b97b08516c],
+            [0x8d44ab5451a0490d, 0xfbee297716e18fa2, 0x1c79b717d069d911, 0x0ff0317c3f08dedb],
+            [0x106dbf72963d5f7b, 0xf662ec9b765ac8b1, 0x6e9eb41f4e4b9121, 0x4121d6ac221b6863],
+        ),
+        niels(
+            [0xad28eb14c1f84ee3, 0x6fb4669e068bda35, 0x604e6f015337e5ea, 0x168efb994a1883e0],
+            [0x7a85ba5fef01ea28, 0x8425a229495d36a8, 0x3a82768a6f79cc52, 0x456c78b658df78c6],
+            [0x2af79c1ceb249b05, 0x1663230233e74ce7, 0x15bed61c41f8fa55, 0x54d8838833a8a100],
+        ),
+        niels(
+            [0x012359b4c9fbd131, 0x5c244299c47760c2, 0x7f0ae9b7fa6189ff, 0x6e3e5068f10fb10c],
+            [0x508ded1debfc678d, 0x14cbe33634a10aae, 0x00dc59bb657f436c, 0x451abe922471e8c9],
+            [0xe24e7a0193e96633, 0xe050fe0601b39afe, 0xe0bef20b97900ad7, 0x2db28e49585257ad],
+        ),
+        niels(
+            [0x724f33ede573ace9, 0x53ffcb45615ec6c2, 0xe23332d841679f8d, 0x51b98eedac912813],
+            [0x2e68165d7cee12d1, 0x62cf7fd59864286c, 0x9ddf17e43341878c, 0x2f10a9974e85e017],
+            [0xf703ec8058a73b25, 0x1ae861c9349604d8, 0x092752b1ef774c00, 0x08ec87ea1eff3227],
+        ),
+        niels(
+            [0x07e46445b1b5468f, 0x245ef92852b2ec83, 0x0c04c3555cea9545, 0x384c0f60ba989759],
+            [0xcc8d32263e1d7f19, 0xbb75d0a1df084d7c, 0x1d575f853606751d, 0x055b34f1b4b4b10c],
+            [0xbcede0ec9fd10023, 0xbbb696033d5f1fa0, 0x39f910cc4b8fc6d3, 0x0cf3d72e1bfe62d5],
+        ),
+        niels(
+            [0x41ca8f59e7d5f523, 0xe3fd19ba8218d226, 0xc0dd21deea6318e4, 0x47609e4f85e1871a],
+            [0x47ba792ccf6b5485, 0x1d81ab3d2288b5a6, 0x993a05e502a11b90, 0x7021ae99bd3deca7],
+            [0x5d045340743ee906, 0xba537e00cd8fdb00, 0x0ff0b5793edc5701, 0x41f9121cb0548dbb],
+        ),
+        niels(
+            [0xf6c109379e79dd33, 0xb06034ee455b3c87, 0xbeb06aca12dd294f, 0x6a219638ee48ea13],
+            [0x94bbac9651421c85, 0x6f0d062d28bdb70d, 0x7ee015bc7cb5af51, 0x02494d2e1bdc7349],
+            [0x20da088a4e43f9e5, 0x32937ba1cb23d7cc, 0xfb4e80ecba907b69, 0x068505f89b8f8e8c],
+        ),
+        niels(
+            [0xd246ea27d18375e3, 0xb105380cbecab27a, 0x9c4a6bc4cbd3eb08, 0x0d0b91f2e28c90af],
+            [0xd9a2243e5873fa8a, 0x83d2f2fd094f707a, 0xb469634e1ea91c98, 0x0dc68c2053eb045c],
+            [0xfe8cd0d1146ec812, 0x950cd8c29c872bcb, 0xb7595071c9101918, 0x0ba479ed0013397d],
+        ),
+        niels(
+            [0x5ffca67f5cb59fa8, 0xa967dee1f17617bc, 0x02e28e6b49a7013e, 0x5e0a478df20b9612],
+            [0xddbe803469baaf69, 0xdd7b94d9e54f77a0, 0xba37a810b2f16126, 0x14cceacd1cc6e29a],
+            [0x31036e27e31d28c0, 0x0b8e6f6528172d45, 0xeeb5015a35df75b7, 0x46d563c494d3e9e0],
+        ),
+        niels(
+            [0xaa5b261eb76ce27f, 0xe2abbcd64fcc9683, 0xf3922e734aaa664d, 0x3b6f002e97ba3d52],
+            [0x114c39aab0ac0a5b, 0x24cf6d7a24dd4179, 0xada41b0761a39fd9, 0x1bbb069fea9b08e1],
+            [0x26859ba7fa542591, 0x8a638ffb479de0c3, 0x9b9e2ec18c0d9987, 0x376dc59ff92a3be5],
+        ),
+        niels(
+            [0x115828c5c4db7969, 0x7c9980ed610c09cc, 0x7589945f6c2effe7, 0x273b25d9bcca2530],
+            [0x98a442ea6168bb3a, 0xd2dada5151401f78, 0xd1915efc7f304da1, 0x1721b833f97b4662],
+            [0x526f739242fbcbf7, 0x6608b7cb65cd728e, 0xd05e36df58ec1d28, 0x1423a33059f8c1e6],
+        ),
+        niels(
+            [0xab4ccd2d9ac0b9aa, 0x9df11a0a1170ffc4, 0x501ad2009f9d6707, 0x3d26f0f7d9cb8f36],
+            [0xe6b63b738680a215, 0x7849524b23834ce5, 0xc69760ab7f4e5869, 0x3b60eb6d4ef67b15],
+            [0xd170379e0e7b6c58, 0xb73ea3890ba79dd0, 0xd1dbda2c0df8f8a0, 0x3f5c7bfbcf41abe9],
+        ),
+        niels(
+            [0x3383ab02630cc25f, 0x6c10f07ecb82b4b9, 0x343662a2b6880741, 0x28163c9938ee5ddf],
+            [0x83fcb33c1c5fd378, 0xf44af0a14fb6d1f6, 0xf53af638a452809c, 0x106a9a0a2cb0e302],
+            [0x44a29dae0382099d, 0x3664032a4a22c2fd, 0xbd964b53c240e64f, 0x1c05cfc6bde61377],
+        ),
+        niels(
+            [0x5aeed82799840a88, 0x479971d23e7d3f3e, 0x8b58048b9bbacf52, 0x424c274dc1d2a996],
+            [0x97440f72275ff7b6, 0x669a536d0f8e0d47, 0x38b700b5ce52d141, 0x337c697bd1f18e8e],
+            [0x99f094aeda2a8bc1, 0x0aed33eaedd33426, 0xe0cf32ccf9069fac, 0x0f0dbe93a4a8885c],
+        ),
+        niels(
+            [0x924c9280c50ecded, 0xeb2c05b678f260e4, 0xbaf867e56a11a2de, 0x68aa5b3363ad80cb],
+            [0x66b4be84537ab6ad, 0xeefaa240f67acac4, 0x4ef612762d75b2d1, 0x55768dea8afbedc1],
+            [0xb99a74b214c63a5e, 0xc713ec4169f0ab91, 0x1efc344096a96976, 0x4d2fdde50ca3943c],
+        ),
+        niels(
+            [0x5e7e1ac7379a37d0, 0xf87cd4d32059e420, 0x7c99e4fa36dff593, 0x07a6a46854c1fedb],
+            [0xf321a6ab86f34aea, 0x40f387866add11f7, 0x7da834e7b11d54a5, 0x6b7ac0cdab1fba61],
+            [0x3b25de60c7f6f607, 0xc91a0e2fa7b37ea2, 0x8a5bf3b9657da391, 0x72ad9b1847814f77],
+        ),
+        niels(
+            [0xb6933f885fb35c12, 0x9541a39d48cc18f5, 0xa5a7c732a343a0fb, 0x6a14bc38888262d5],
+            [0xfa743014858a286a, 0x77d5dcd11be453b5, 0x3c28f560b378e155, 0x711683c7bafba902],
+            [0xf20312b4d724ecf0, 0xc39357ffbc753296, 0x3747e678fbc23fcd, 0x7071971e2d560c78],
+        ),
+        niels(
+            [0x9b7be98512d80033, 0xd8a717347ee0414a, 0x5c4199ce59cbf511, 0x38fddddf72431512],
+            [0x23b8e2235a214e9d, 0xd9c323595c86eb06, 0x4e4e71806f00305a, 0x54f3d5846df1b861],
+            [0x910f82840981bcfe, 0x9bf93cd850368bf7, 0xc496c53ffe548558, 0x41fcf4ea4ed3bdb9],
+        ),
+        niels(
+            [0xb4f20319824e6c63, 0xfc1885541d7f5425, 0xa136502387221539, 0x18bd02f09c522ae3],
+            [0x9bc1ea4c70c35157, 0xe36d906c563e5e86, 0x8e590452f3d18011, 0x3e70df80351d6551],
+            [0xbf424c3e915cbc9d, 0x127d7d7f339975b9, 0xfccc2cbda31cc66f, 0x661e9162f9fd3ab0],
+        ),
+        niels(
+            [0x9752947721edc6c8, 0x47283e7bcddcfec1, 0xcb59655754658c0a, 0x5d706762bbe990f7],
+            [0x8616683f2fb6e9dd, 0x9d5bd05d50268a36, 0x330d8c74fd99a067, 0x21e1a6f78ba4e853],
+            [0x1d42a115623d0b25, 0xd7530b5794b595ed, 0x2d7e58cc5d8f1776, 0x62fa5307207d8f4b],
+        ),
+        niels(
+            [0x1c91c3f2e822c87d, 0x85d12ff1353f1668, 0x437f3ce2d504e1a8, 0x405e6e04a36d7ba2],
+            [0x982f49c986d8208b, 0xfd8cb3df4a4b6cce, 0x7fedeb75a10aca41, 0x4ca743ca9717f4da],
+            [0x1c6eef1f0b6bd01f, 0x31d05839a2e42c8a, 0x8262010d4d8a2244, 0x4600f5733b667fe7],
+        ),
+        niels(
+            [0x4e76ba3483aa5e42, 0xdd151e285c87a0cd, 0x258afea77b939152, 0x3b48a09d1e917326],
+            [0xed69ed821c66b5d4, 0x81f05a045fc7fd40, 0x8c250ec71b28a518, 0x5e8d5995f57f0b1b],
+            [0x02ccba4ece21817f, 0xc011b3ebf5972251, 0xbb06964c9402a6bb, 0x355ad92bf48a7836],
+        ),
+        niels(
+            [0xe1108eda7cc44187, 0x086a099ed8ba9e02, 0xa011bcdbdd74578c, 0x1bdb5f0156f82cfd],
+            [0x320feffa19d39206, 0x38d6fc71becc76c4, 0xf83eb17fa7bec8fa, 0x282cde6ab138a963],
+            [0x42d70728f77809c9, 0x3c7410bfc956475e, 0x23ec7eb90d57f572, 0x3ce9d23d6b0ccc15],
+        ),
+        niels(
+            [0x086cab3622a92991, 0x4e672ffcfdddcaf6, 0x82fa642ac8c54dbd, 0x36dc42baf44c8b47],
+            [0x16b84520863d0ecb, 0xc2693b3ba71de954, 0x27e611ce2b66378a, 0x583eec6b0bda0df7],
+            [0x4332e83851a47cf9, 0xa4a100961cb84a4e, 0x5e75198cb032695a, 0x130f663367dd4039],
+        ),
+        niels(
+            [0x954d21d2d5e106a6, 0xf6b185caddfbbb4d, 0x84e33a292e325eaa, 0x5ecb25e24f5da5bf],
+            [0x75ffed6cf4a38b71, 0x536898dda7b0c6f4, 0xfb117e76b4c86e75, 0x1133b2176c9d27c5],
+            [0xf573439beef63293, 0x5f3e58fcc4c6a556, 0x4e512681427067a0, 0x38db3aa5300cc7e2],
+        ),
+        niels(
+            [0x270e0a974955db1b, 0x618ad2bdaf3745be, 0x6619915e10691cbb, 0x5b044626b966fa0b],
+            [0xb56c636e94f83960, 0x95761153e67351da, 0x21e333d50e6d05e1, 0x64c5df09040574d5],
+            [0x1b5f2fdcf9f47dff, 0x70aa614a395d9411, 0xb13de0ba5afa37f3, 0x687aed7e778b4411],
+        ),
+        niels(
+            [0xee141dac437757bf, 0xa6351247c07a4a40, 0xf99e400cf420e625, 0x4ea002d5ef165a89],
+            [0xee7100ed87ca2566, 0xf03afae23360868f, 0x5b2ea8dc7b2ccbcb, 0x5ea8968ecadb28e9],
+            [0xf5dda0ddabb187ff, 0xe1c544c8de24995d, 0x8c6782e56ce88f71, 0x0613424d8c63abc1],
+        ),
+        niels(
+            [0x5e548e162e0b143f, 0x060f13bf1794cc71, 0xd5f7b355ab465775, 0x588d776fde73c2d3],
+            [0x879dda0430c13fc1, 0xf5404e446dd7ab6a, 0x26080d6bd21d5210, 0x6f576dbc39aaf3c3],
+            [0x980f799ff35f1d2b, 0x74d33ab6e60bd4e6, 0xa16e22c4dbd337a5, 0x509cdfa798727e46],
+        ),
+        niels(
+            [0x0e2f3779429b1fad, 0x18d6d8e91a13e336, 0x9476813e9a955367, 0x0b61c3aca5a11697],
+            [0xd5e6fb0a63f80f63, 0xb07da4db7b674f47, 0x75bd7dbff03781df, 0x717bb9072db75b78],
+            [0x24562ef629faf3d5, 0xa4a44143c917885c, 0xd994082262db84b7, 0x7356e9b1c068ac24],
+        ),
+        niels(
+            [0x559e2fa63bb42c5d, 0x280c795bf51429e8, 0x3c223fea37df2100, 0x17c07ac254326df1],
+            [0x694d3a6261eb0b1f, 0xf888e59acf7d3ff1, 0x441335f21b5ddace, 0x5aad396d65e0d8ab],
+            [0x27dd601e35dfa745, 0x633e8c7af657e694, 0x903ce136591f829b, 0x111112804162f812],
+        ),
+        niels(
+            [0x0052f6cc8888827e, 0x1cede61ac59ba690, 0x942920fc8390744d, 0x00d958767a78fef4],
+            [0x3500f3ce8d824106, 0x056432d985e294ba, 0xf178dd8261de45a0, 0x0424ff52d43d7879],
+            [0x71aef1842276394c, 0x39a60a85e46f2dfc, 0x8d3e9dc1f7f70e1e, 0x2f2f26a6339c2110],
+        ),
+        niels(
+            [0xae857a93da1ebac2, 0xd53dd90319c496ae, 0x4f910d25e121e220, 0x1e1566680e8d1123],
+            [0x408798e30aa7ba73, 0x16694069948c08d7, 0xa744e5a8ff024869, 0x32bf1633558106a1],
+            [0xec74393e84cbacfb, 0x35cffdabc8a840f9, 0x55bca09482055090, 0x3da818b3e6173715],
+        ),
+        niels(
+            [0xfe09d7aba6931aba, 0xe2a752b7b5fc3bc4, 0x210be0dc60e3e9ad, 0x4c542cc59907cda9],
+            [0xf74036d2d4a8936e, 0x448fe80b5e2b7003, 0x01774294d096ce56, 0x49e3e31672aadca5],
+            [0x7ca0988d80157123, 0xc0192acb316ae9a8, 0xd2e1f7fe4fc4f71a, 0x185522b251ad447b],
+        ),
+        niels(
+            [0x5efb7b29a963bd12, 0xd6e2d5f81e83f8a9, 0x297de69cfc4225be, 0x3a0be3177159da8d],
+            [0xbd77fd91d1d70461, 0x8022ae76220851e3, 0x93eb0ee7bf12f267, 0x38962f848c95d459],
+            [0x1c2994ff327bf8f5, 0x091f3540249404a9, 0x49f8c60e5e7729ec, 0x2f881e0d77c91e4a],
+        ),
+        niels(
+            [0x479362984edae639, 0x604dcbccbf3ea854, 0x8ddb7d6b7a04af0a, 0x107b99d4322a9bc8],
+            [0x21254c25d51e6cd0, 0x2623935bc19e2da5, 0x6a7b0550d8a556bf, 0x5686d070782f5056],
+            [0x6b862526bf90d0df, 0x40ad6ea6d2946e36, 0xc1d59f72a148de5f, 0x5c83e782b2323a94],
+        ),
+        niels(
+            [0x251facb1a433139c, 0x35a2bce9577f3e56, 0x5d8419a07f0fd68c, 0x347f5b87d44906d4],
+            [0xa7f36710d4184340, 0xc3044a9789a81919, 0x35d581d372e2e519, 0x30db10eb581622be],
+            [0xdb2dbae501cbec0f, 0x4acb655053fc0ca6, 0xfb803fe697a2d936, 0x6b315b84019fbbb7],
+        ),
+        niels(
+            [0x057745331a874430, 0x73bfc4ae41bf1ae9, 0xa008887c23dda8de, 0x6706b5dbf8fc5c74],
+            [0x84622a213d250fab, 0x09ce3bdfacb9bd9b, 0x2274a4577ac150f9, 0x0ea6b8b471dde4c2],
+            [0xe1f8e366834b90a1, 0xbfb8472e2ae7a1c3, 0xc395c5f6764aeb35, 0x48a539a513fb6944],
+        ),
+        niels(
+            [0x389a08cb90e3ac20, 0x4255853dee36c5d4, 0x302ef6aff760f7cd, 0x363f60dd4680ac6f],
+            [0xd386de4756740bfb, 0x2dca5c7477ca144b, 0x1261d12c8231f1a2, 0x0ca82c2f44d95b4c],
+            [0x36a685b8edfe550b, 0xb7e918b2b1fa42d5, 0x29965723fd14f0de, 0x2adacef1b9b717ff],
+        ),
+        niels(
+            [0x6655e574cfb24e0f, 0x8658aa66bfc64f77, 0xbb36de00ecf6c2d8, 0x39649f4467be9d05],
+            [0x695693ab12370c3d, 0xdc0f9b372673b4be, 0xf1897a4edc7e6a90, 0x29d003a113a31d8a],
+            [0x4162cbdc77444f89, 0x4fd2e6868d94fdc3, 0xf3c330a2dd20120f, 0x10028ea7189c44f5],
+        ),
+        niels(
+            [0xf4f81e4eaf0d3f6e, 0x0920e6c22f3e51f3, 0x0d566146232e0fe3, 0x039c9c9960403d20],
+            [0x70c457550b613b8d, 0xb9c523d87f49d6e9, 0x1e016b912fc95646, 0x0747cc05d35761b9],
+            [0x369a8dee53b6d889, 0x46ddd46696d7b761, 0xf0bc2afe4dbe8f16, 0x38fae6025f225f0e],
+        ),
+        niels(
+            [0x000bcd9d5239f21b, 0x8a4dfc821d4c5c2b, 0xf3693513996d2186, 0x4627689422236f35],
+            [0x0f9a052eb798bd26, 0x6a5dd10114923de4, 0x0b4ab07ec656ecb7, 0x680ec0634e78ba83],
+            [0x3359d7a26f974d79, 0x7314b09f6771976b, 0x65b5ff4fac52d5ce, 0x248539dac31cf151],
+        ),
+        niels(
+            [0x5b51345988dfd82e, 0xa724f778109165fb, 0xe25e7bf9c6b06fff, 0x07d2c645e7b55748],
+            [0x7d51e970463b3040, 0x9ae7fea98267bc57, 0x981337c9fdd85cbc, 0x15843507c6c80870],
+            [0x87146c405cda9701, 0x2457902889b122e6, 0x4791880db6175e1c, 0x67de80f45bd8888a],
+        ),
+    ]),
+};
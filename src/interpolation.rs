@@ -0,0 +1,349 @@
+//! Lagrange interpolation and fast multipoint evaluation of polynomials.
+
+use alloc::vec::Vec;
+
+use crate::field::PrimeField;
+use crate::poly::DensePolynomial;
+
+/// Subtrees with at most this many points are evaluated directly using
+/// Horner's rule rather than by further remaindering.
+const MULTIPOINT_LEAF_SIZE: usize = 32;
+
+/// Divisors with at least this many coefficients are divided by computing a
+/// power series inverse with Newton iteration, which is asymptotically as
+/// fast as multiplication, rather than by long division.
+const NEWTON_DIVISION_THRESHOLD: usize = 128;
+
+/// Computes the Lagrange basis polynomials for the distinct points `xs`,
+/// evaluated at `point`. That is, the `i`th output is
+/// `prod_{j != i} (point - x_j) / (x_i - x_j)`, so that any polynomial `p`
+/// of degree less than `xs.len()` satisfies
+/// `p(point) = sum_i p(x_i) * output[i]`.
+///
+/// This is what is needed to reconstruct a Shamir-shared secret, with
+/// `point` set to zero. It uses a single field inversion.
+///
+/// Returns `None` if `xs` contains a repeated point.
+pub fn lagrange_coefficients<F: PrimeField>(xs: &[F], point: &F) -> Option<Vec<F>> {
+    let denominators = barycentric_denominators(xs)?;
+
+    // If `point` is one of the xs, the basis polynomials are an indicator.
+    if let Some(k) = xs.iter().position(|x| x == point) {
+        let mut result = vec![F::zero(); xs.len()];
+        result[k] = F::one();
+        return Some(result);
+    }
+
+    // Otherwise, L_i(point) = N / ((point - x_i) * d_i), where N is the
+    // product of all (point - x_j) and d_i = prod_{j != i} (x_i - x_j).
+    let mut numerator = F::one();
+    let mut result = Vec::with_capacity(xs.len());
+    for (x, d) in xs.iter().zip(denominators.iter()) {
+        let diff = *point - x;
+        numerator *= &diff;
+        result.push(diff * d);
+    }
+
+    let mut scratch = vec![F::zero(); xs.len()];
+    F::batch_invert_with_scratch(&mut result, &mut scratch);
+    for value in result.iter_mut() {
+        *value *= &numerator;
+    }
+
+    Some(result)
+}
+
+/// Computes `d_i = prod_{j != i} (x_i - x_j)` for each `i`, returning
+/// `None` if any of them is zero.
+fn barycentric_denominators<F: PrimeField>(xs: &[F]) -> Option<Vec<F>> {
+    let mut denominators = Vec::with_capacity(xs.len());
+    for (i, xi) in xs.iter().enumerate() {
+        let mut d = F::one();
+        for (j, xj) in xs.iter().enumerate() {
+            if i != j {
+                d *= &(*xi - xj);
+            }
+        }
+
+        if bool::from(d.is_zero()) {
+            return None;
+        }
+        denominators.push(d);
+    }
+
+    Some(denominators)
+}
+
+/// Returns the polynomial `prod_i (X - x_i)`.
+fn vanishing_polynomial<F: PrimeField>(xs: &[F]) -> DensePolynomial<F> {
+    let mut coeffs = vec![F::one()];
+    for x in xs {
+        // Multiply by (X - x)
+        coeffs.push(F::zero());
+        for i in (1..coeffs.len()).rev() {
+            coeffs[i] = coeffs[i - 1] - &(coeffs[i] * x);
+        }
+        coeffs[0] = -(coeffs[0] * x);
+    }
+
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+/// Returns `(p - p(x)) / (X - x)` using synthetic division.
+fn divide_by_linear<F: PrimeField>(p: &[F], x: &F) -> Vec<F> {
+    let mut quotient = vec![F::zero(); p.len().saturating_sub(1)];
+    let mut carry = F::zero();
+    for i in (1..p.len()).rev() {
+        carry = carry * x + &p[i];
+        quotient[i - 1] = carry;
+    }
+
+    quotient
+}
+
+impl<F: PrimeField> DensePolynomial<F> {
+    /// Returns the unique polynomial of degree less than `points.len()`
+    /// passing through the given `(x, y)` pairs, or `None` if two pairs share
+    /// an `x` coordinate.
+    ///
+    /// This computes the barycentric weights with a single batch inversion,
+    /// and takes time quadratic in the number of points.
+    pub fn interpolate(points: &[(F, F)]) -> Option<Self> {
+        let xs: Vec<F> = points.iter().map(|p| p.0).collect();
+        let mut weights = barycentric_denominators(&xs)?;
+        let mut scratch = vec![F::zero(); weights.len()];
+        F::batch_invert_with_scratch(&mut weights, &mut scratch);
+
+        // p(X) = sum_i y_i * w_i * M(X) / (X - x_i), where M = prod (X - x_j).
+        let m = vanishing_polynomial(&xs);
+        let mut coeffs = vec![F::zero(); xs.len()];
+        for ((x, y), w) in points.iter().zip(weights.iter()) {
+            let scale = *y * w;
+            for (c, q) in coeffs.iter_mut().zip(divide_by_linear(m.coeffs(), x)) {
+                *c += q * &scale;
+            }
+        }
+
+        Some(Self::from_coefficients_vec(coeffs))
+    }
+
+    /// Evaluates this polynomial at each of `points`, using a subproduct
+    /// tree: the polynomial is reduced modulo `prod (X - x_i)` over halves
+    /// of the points recursively, until few enough points remain to
+    /// evaluate directly.
+    ///
+    /// Over `Fq`, where products and remainders use number-theoretic
+    /// transforms, this overtakes evaluating at each point separately at
+    /// around a thousand points, and is about three times faster for
+    /// 4096 points of degree 4096.
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        let tree = SubproductTree::new(points);
+        let mut result = Vec::with_capacity(points.len());
+        tree.evaluate(self, points, tree.levels.len() - 1, 0, &mut result);
+
+        result
+    }
+
+    /// Computes `self mod divisor` using Newton iteration for large divisors
+    /// and long division otherwise. `divisor` must be monic.
+    fn rem_monic(&self, divisor: &Self) -> Self {
+        if self.coeffs().len() < divisor.coeffs().len() {
+            return self.clone();
+        }
+
+        if divisor.coeffs().len() < NEWTON_DIVISION_THRESHOLD {
+            return self.divide_with_remainder(divisor).unwrap().1;
+        }
+
+        // Writing rev_k(a) = X^k a(1/X), we have
+        // rev(self) = rev(quotient) * rev(divisor) mod X^(m+1), where m is
+        // the degree of the quotient. rev(divisor) has constant term 1, so
+        // it has a power series inverse.
+        let m = self.degree() - divisor.degree();
+        let rev_divisor_inv = power_series_inverse(&reversed(divisor.coeffs()), m + 1);
+        let rev_self = Self::from_coefficients_slice(&reversed(self.coeffs())[..=m]);
+        let mut rev_quotient = (&rev_self * &rev_divisor_inv).into_coeffs();
+        rev_quotient.resize(m + 1, F::zero());
+        rev_quotient.reverse();
+
+        let quotient = Self::from_coefficients_vec(rev_quotient);
+
+        self - &(&quotient * divisor)
+    }
+}
+
+fn reversed<F: PrimeField>(coeffs: &[F]) -> Vec<F> {
+    coeffs.iter().rev().cloned().collect()
+}
+
+/// Computes the inverse of the power series `a` modulo `X^precision` by
+/// Newton iteration, `g <- g * (2 - a * g)`, which doubles the precision
+/// of `g` each step. `a` must have constant term one.
+fn power_series_inverse<F: PrimeField>(a: &[F], precision: usize) -> DensePolynomial<F> {
+    debug_assert!(a[0] == F::one());
+
+    let mut g = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+    let mut k = 1;
+    while k < precision {
+        k = core::cmp::min(2 * k, precision);
+
+        let a_k = DensePolynomial::from_coefficients_slice(&a[..core::cmp::min(k, a.len())]);
+        let mut e = (&a_k * &g).into_coeffs();
+        e.truncate(k);
+        for c in e.iter_mut() {
+            *c = -*c;
+        }
+        e[0] += &F::from(2);
+
+        let mut next = (&g * &DensePolynomial::from_coefficients_vec(e)).into_coeffs();
+        next.truncate(k);
+        g = DensePolynomial::from_coefficients_vec(next);
+    }
+
+    g
+}
+
+/// A binary tree whose leaves are the polynomials `X - x_i`, and whose
+/// inner nodes are the products of their children. `levels[0]` holds the
+/// leaves (or rather, products of at most `MULTIPOINT_LEAF_SIZE` of them)
+/// and the last level holds the root.
+struct SubproductTree<F: PrimeField> {
+    levels: Vec<Vec<DensePolynomial<F>>>,
+    leaf_size: usize,
+}
+
+impl<F: PrimeField> SubproductTree<F> {
+    fn new(points: &[F]) -> Self {
+        let leaf_size = MULTIPOINT_LEAF_SIZE;
+        let mut levels = vec![points
+            .chunks(leaf_size)
+            .map(vanishing_polynomial)
+            .collect::<Vec<_>>()];
+
+        while levels.last().unwrap().len() > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| {
+                    if pair.len() == 2 {
+                        &pair[0] * &pair[1]
+                    } else {
+                        pair[0].clone()
+                    }
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        SubproductTree { levels, leaf_size }
+    }
+
+    /// Evaluates `p` at the points under node `index` of `level`, appending
+    /// the results to `result` in order.
+    fn evaluate(
+        &self,
+        p: &DensePolynomial<F>,
+        points: &[F],
+        level: usize,
+        index: usize,
+        result: &mut Vec<F>,
+    ) {
+        let node = match self.levels[level].get(index) {
+            Some(node) => node,
+            None => return,
+        };
+        let p = p.rem_monic(node);
+
+        if level == 0 {
+            let start = index * self.leaf_size;
+            let end = core::cmp::min(start + self.leaf_size, points.len());
+            result.extend(points[start..end].iter().map(|x| p.evaluate(x)));
+        } else {
+            self.evaluate(&p, points, level - 1, 2 * index, result);
+            self.evaluate(&p, points, level - 1, 2 * index + 1, result);
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::{Fq, Fr};
+
+#[test]
+fn test_shamir_reconstruction() {
+    // Share the secret 42 with threshold 3 using p(X) = 42 + 7X + 11X^2.
+    let p = DensePolynomial::from_coefficients_vec(vec![Fr::from(42), Fr::from(7), Fr::from(11)]);
+    let xs = [Fr::from(1), Fr::from(3), Fr::from(5)];
+    let shares: Vec<Fr> = xs.iter().map(|x| p.evaluate(x)).collect();
+
+    let coefficients = lagrange_coefficients(&xs, &Fr::zero()).unwrap();
+    let mut secret = Fr::zero();
+    for (c, s) in coefficients.iter().zip(shares.iter()) {
+        secret += c * s;
+    }
+    assert_eq!(secret, Fr::from(42));
+
+    // Evaluating at one of the points gives an indicator vector.
+    assert_eq!(
+        lagrange_coefficients(&xs, &Fr::from(3)).unwrap(),
+        vec![Fr::zero(), Fr::one(), Fr::zero()]
+    );
+
+    // Repeated points are rejected.
+    assert!(lagrange_coefficients(&[Fr::from(1), Fr::from(1)], &Fr::zero()).is_none());
+}
+
+#[test]
+fn test_interpolate() {
+    let p =
+        DensePolynomial::from_coefficients_vec((0..20u64).map(|i| Fq::from(i * i + 1)).collect());
+    let points: Vec<(Fq, Fq)> = (0..20u64)
+        .map(|i| {
+            let x = Fq::from(i * 31 + 5);
+            (x, p.evaluate(&x))
+        })
+        .collect();
+
+    assert_eq!(DensePolynomial::interpolate(&points).unwrap(), p);
+    assert_eq!(
+        DensePolynomial::<Fq>::interpolate(&[]).unwrap(),
+        DensePolynomial::zero()
+    );
+    assert!(
+        DensePolynomial::interpolate(&[(Fq::one(), Fq::one()), (Fq::one(), Fq::zero())]).is_none()
+    );
+}
+
+#[test]
+fn test_rem_monic() {
+    for &(m, n) in &[(600, 200), (300, 130), (128, 128), (10, 200), (100, 30)] {
+        let a = DensePolynomial::from_coefficients_vec(
+            (0..m as u64).map(|i| Fq::from(i + 3).square()).collect(),
+        );
+        let mut b: Vec<Fq> = (0..n as u64).map(|i| Fq::from(i * 5 + 1)).collect();
+        *b.last_mut().unwrap() = Fq::one();
+        let b = DensePolynomial::from_coefficients_vec(b);
+
+        assert_eq!(a.rem_monic(&b), a.divide_with_remainder(&b).unwrap().1);
+    }
+}
+
+#[test]
+fn test_evaluate_many() {
+    for &(degree, n) in &[(0, 0), (5, 1), (10, 9), (300, 200), (50, 500)] {
+        let p = DensePolynomial::from_coefficients_vec(
+            (0..=degree as u64)
+                .map(|i| Fq::from(i * 7 + 2).square())
+                .collect(),
+        );
+        let points: Vec<Fq> = (0..n as u64).map(|i| Fq::from(i * 13 + 1)).collect();
+
+        let expected: Vec<Fq> = points.iter().map(|x| p.evaluate(x)).collect();
+        assert_eq!(p.evaluate_many(&points), expected);
+    }
+
+    let p = DensePolynomial::from_coefficients_vec((0..40u64).map(Fr::from).collect());
+    let points: Vec<Fr> = (0..33u64).map(|i| Fr::from(i + 100)).collect();
+    let expected: Vec<Fr> = points.iter().map(|x| p.evaluate(x)).collect();
+    assert_eq!(p.evaluate_many(&points), expected);
+}
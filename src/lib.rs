@@ -5,9 +5,24 @@
 //!
 //! * `AffinePoint` / `ExtendedPoint` which are implementations of Jubjub group arithmetic
 //! * `AffineNielsPoint` / `ExtendedNielsPoint` which are pre-processed Jubjub points
+//! * `SubgroupPoint`, an `ExtendedPoint` known to be in the prime-order subgroup
+//! * `LookupTable`, a serializable table of multiples of a point for repeated multiplication
+//! * `BasepointTable`, a precomputed table for fast multiplication of a fixed point, and
+//!   `FixedBaseMsm` for multi-scalar multiplication over fixed generators (requires `alloc`)
 //! * `Fq`, which is the base field of Jubjub
 //! * `Fr`, which is the scalar field of Jubjub
+//! * `Field` / `PrimeField`, traits implemented by `Fq` and `Fr` for writing field-generic code
+//! * `EvaluationDomain`, for radix-2 number-theoretic transforms over `Fq`
+//! * `DensePolynomial`, for polynomial arithmetic over `Fq` and `Fr` (requires `alloc`)
+//! * `lagrange_coefficients` and `DensePolynomial::interpolate` / `evaluate_many` for
+//!   interpolation and multipoint evaluation (requires `alloc`)
+//! * `ExtendedPoint::multiscalar_mul` / `multiscalar_mul_vartime` for multi-scalar
+//!   multiplication (requires `alloc`), and `multiscalar_mul_array` for a fixed number of
+//!   terms without it
 //! * `batch_normalize` for converting many `ExtendedPoint`s into `AffinePoint`s efficiently.
+//! * `batch_normalize_parallel` and `ExtendedPoint::multiscalar_mul_parallel` /
+//!   `multiscalar_mul_vartime_parallel`, which spread work across threads (requires
+//!   `parallel`)
 //!
 //! # Constant Time
 //!
@@ -22,27 +37,104 @@
 //!
 //! # Features
 //!
+//! * `std` (enabled by default): This enables APIs that need the Rust standard library. It
+//!   implies `alloc`.
+//! * `alloc`: This enables APIs that allocate, such as `DensePolynomial`,
+//!   `Fq::batch_invert` and `ExtendedPoint::multiscalar_mul`, for `no_std` targets that have
+//!   a global allocator.
+//! * `parallel`: This enables multithreaded APIs, such as `batch_normalize_parallel`, which
+//!   use scoped threads and so need Rust 1.63 or later. It implies `std`.
 //! * `nightly`: This enables `subtle/nightly` which attempts to prevent the compiler from
-//! performing optimizations that could compromise constant time arithmetic. It is
-//! recommended to enable this if you are able to use a nightly version of the Rust compiler.
+//!   performing optimizations that could compromise constant time arithmetic. It is
+//!   recommended to enable this if you are able to use a nightly version of the Rust compiler.
 
 #![no_std]
 
 #[cfg(feature = "std")]
-#[macro_use]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+#[macro_use]
+extern crate alloc;
+
 use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[macro_use]
 mod util;
 
+#[macro_use]
+mod montgomery;
+
+mod binary_gcd;
+mod safegcd;
+mod window;
+
+// The items that `impl_montgomery_field!` refers to in the crates that
+// invoke it. They are not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::vec;
+    pub use byteorder;
+    pub use core;
+    pub use subtle;
+
+    pub use crate::util::{adc, mac, sbb};
+
+    pub mod binary_gcd {
+        pub use crate::binary_gcd::invert_vartime;
+    }
+
+    pub mod safegcd {
+        pub use crate::safegcd::invert;
+    }
+}
+
 mod fq;
 mod fr;
 pub use fq::*;
 pub use fr::*;
 
+mod field;
+pub use field::{Field, PrimeField};
+
+mod domain;
+pub use domain::EvaluationDomain;
+
+#[cfg(feature = "alloc")]
+mod poly;
+#[cfg(feature = "alloc")]
+pub use poly::DensePolynomial;
+
+#[cfg(feature = "alloc")]
+mod interpolation;
+#[cfg(feature = "alloc")]
+pub use interpolation::lagrange_coefficients;
+
+mod msm;
+
+// Scoped threads need Rust 1.63, which only users of `parallel` require.
+#[cfg(feature = "parallel")]
+#[clippy::msrv = "1.63"]
+mod parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::batch_normalize_parallel;
+
+mod basepoint;
+mod generator_table;
+pub use basepoint::BasepointTable;
+#[cfg(feature = "alloc")]
+pub use basepoint::FixedBaseMsm;
+
+mod lookup;
+pub use lookup::LookupTable;
+
+mod subgroup;
+pub use subgroup::SubgroupPoint;
+
+use window::{NafLookupTable5, WindowTable};
+
 /// This represents a Jubjub point in the affine `(u, v)`
 /// coordinates.
 #[derive(Clone, Copy, Debug)]
@@ -207,6 +299,21 @@ impl AffineNielsPoint {
     }
 }
 
+impl Neg for AffineNielsPoint {
+    type Output = AffineNielsPoint;
+
+    /// Computes the negation of a point `P = (u, v)` as `-P = (-u, v)`,
+    /// which swaps `v + u` and `v - u` and negates `u * v * 2d`.
+    #[inline]
+    fn neg(self) -> AffineNielsPoint {
+        AffineNielsPoint {
+            v_plus_u: self.v_minus_u,
+            v_minus_u: self.v_plus_u,
+            t2d: -self.t2d,
+        }
+    }
+}
+
 impl ConditionallySelectable for AffineNielsPoint {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         AffineNielsPoint {
@@ -227,6 +334,23 @@ pub struct ExtendedNielsPoint {
     t2d: Fq,
 }
 
+impl Neg for ExtendedNielsPoint {
+    type Output = ExtendedNielsPoint;
+
+    /// Computes the negation of a point `P = (U, V, Z, T)` as
+    /// `-P = (-U, V, Z, -T)`, which swaps `V + U` and `V - U` and
+    /// negates `T * 2d`.
+    #[inline]
+    fn neg(self) -> ExtendedNielsPoint {
+        ExtendedNielsPoint {
+            v_plus_u: self.v_minus_u,
+            v_minus_u: self.v_plus_u,
+            z: self.z,
+            t2d: -self.t2d,
+        }
+    }
+}
+
 impl ConditionallySelectable for ExtendedNielsPoint {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         ExtendedNielsPoint {
@@ -268,6 +392,14 @@ const EDWARDS_D2: Fq = Fq([
     0x3c0445fed27ecf14,
 ]);
 
+// `1/2`
+const HALF: Fq = Fq([
+    0x00000000ffffffff,
+    0xac425bfd0001a401,
+    0xccc627f7f65e27fa,
+    0x0c1258acd66282b7,
+]);
+
 impl AffinePoint {
     /// Constructs the neutral element `(0, 1)`.
     pub fn identity() -> Self {
@@ -289,6 +421,32 @@ impl AffinePoint {
         tmp
     }
 
+    /// Attempts to interpret a byte representation of an
+    /// affine point, failing if the element is not on
+    /// the curve or non-canonical.
+    pub fn from_bytes(mut b: [u8; 32]) -> CtOption<Self> {
+        // Grab the sign bit from the representation
+        let sign = b[31] >> 7;
+
+        // Mask away the sign bit
+        b[31] &= 0b0111_1111;
+
+        // Interpret what remains as the v-coordinate
+        Fq::from_bytes(b).and_then(|v| {
+            // See `from_bytes_vartime` for the derivation of
+            // u^2 = (v^2 - 1) / (1 + d.v^2), where the denominator
+            // is always nonzero.
+            let v2 = v.square();
+            let (is_square, u) = Fq::sqrt_ratio(&(v2 - Fq::one()), &(Fq::one() + EDWARDS_D * &v2));
+
+            // Fix the sign of `u` if necessary
+            let flip_sign = Choice::from((u.into_bytes()[0] ^ sign) & 1);
+            let u = Fq::conditional_select(&u, &-u, flip_sign);
+
+            CtOption::new(AffinePoint { u, v }, is_square)
+        })
+    }
+
     /// Attempts to interpret a byte representation of an
     /// affine point, failing if the element is not on
     /// the curve or non-canonical.
@@ -299,7 +457,7 @@ impl AffinePoint {
         let sign = b[31] >> 7;
 
         // Mask away the sign bit
-        b[31] &= 0b01111_1111;
+        b[31] &= 0b0111_1111;
 
         // Interpret what remains as the v-coordinate
         match Fq::from_bytes_vartime(b) {
@@ -317,10 +475,8 @@ impl AffinePoint {
 
                 let v2 = v.square();
 
-                match ((v2 - Fq::one()) * (Fq::one() + EDWARDS_D * &v2).invert_nonzero())
-                    .sqrt_vartime()
-                {
-                    Some(mut u) => {
+                match Fq::sqrt_ratio(&(v2 - Fq::one()), &(Fq::one() + EDWARDS_D * &v2)) {
+                    (is_square, mut u) if bool::from(is_square) => {
                         // Fix the sign of `u` if necessary
                         if (u.into_bytes()[0] & 1) != sign {
                             u = -u;
@@ -328,7 +484,7 @@ impl AffinePoint {
 
                         Some(AffinePoint { u, v })
                     }
-                    None => None,
+                    _ => None,
                 }
             }
             None => None,
@@ -384,6 +540,28 @@ impl ExtendedPoint {
         self.double().double().double()
     }
 
+    /// Returns true if this element is the identity.
+    pub fn is_identity(&self) -> Choice {
+        self.ct_eq(&ExtendedPoint::identity())
+    }
+
+    /// Returns true if this element is of small order, i.e. if it is
+    /// killed by the cofactor `8`.
+    pub fn is_small_order(&self) -> Choice {
+        self.mul_by_cofactor().is_identity()
+    }
+
+    /// Returns true if this element is in the prime-order subgroup, i.e.
+    /// if it has no small-order component. This costs about as much as a
+    /// scalar multiplication.
+    pub fn is_torsion_free(&self) -> Choice {
+        // The subgroup has order r, so we check whether r * P is the
+        // identity. The radix-16 digits of the scalar -1 are those of the
+        // integer r - 1, so this computes (r - 1) * P + P exactly even
+        // when P is not in the subgroup.
+        (self * -Fr::one() + self).is_identity()
+    }
+
     /// Performs a pre-processing step that produces an `ExtendedNielsPoint`
     /// for use in multiple additions.
     pub fn to_niels(&self) -> ExtendedNielsPoint {
@@ -395,6 +573,85 @@ impl ExtendedPoint {
         }
     }
 
+    /// Computes the table `[P, 2P, ..., 8P]` of multiples of this point,
+    /// for use in windowed scalar multiplication.
+    fn window_table(&self) -> WindowTable<ExtendedNielsPoint> {
+        let base = self.to_niels();
+        let mut points = [base; 8];
+        let mut acc = *self;
+        for point in points.iter_mut().skip(1) {
+            acc += base;
+            *point = acc.to_niels();
+        }
+
+        WindowTable(points)
+    }
+
+    /// Computes the table `[P, 3P, 5P, ..., 15P]` of odd multiples of this
+    /// point, for use in NAF scalar multiplication.
+    fn naf_table(&self) -> NafLookupTable5<ExtendedNielsPoint> {
+        let double = self.double().to_niels();
+        let mut points = [self.to_niels(); 8];
+        let mut acc = *self;
+        for point in points.iter_mut().skip(1) {
+            acc += double;
+            *point = acc.to_niels();
+        }
+
+        NafLookupTable5(points)
+    }
+
+    /// Multiplies this point by a scalar using its width-5 non-adjacent
+    /// form, which needs about 50 additions rather than the 70 of the
+    /// constant time `Mul`. This is intended for public scalars, such as
+    /// when verifying signatures.
+    ///
+    /// **This operation is variable time with respect to the scalar.**
+    pub fn mul_vartime(&self, by: &Fr) -> ExtendedPoint {
+        let table = self.naf_table();
+        let naf = by.to_wnaf(5);
+
+        let mut acc = ExtendedPoint::identity();
+        let top = match naf.iter().rposition(|digit| *digit != 0) {
+            Some(top) => top,
+            None => return acc,
+        };
+
+        for digit in naf[..=top].iter().rev() {
+            acc = acc.double();
+            if *digit > 0 {
+                acc += table.select_vartime(*digit as usize);
+            } else if *digit < 0 {
+                acc -= table.select_vartime(-*digit as usize);
+            }
+        }
+
+        acc
+    }
+
+    /// This is the double-and-add point multiplication that preceded the
+    /// windowed implementation, kept as a reference for testing.
+    #[cfg(test)]
+    fn mul_double_and_add(&self, other: &Fr) -> ExtendedPoint {
+        let zero = ExtendedPoint::identity().to_niels();
+        let base = self.to_niels();
+
+        let mut acc = ExtendedPoint::identity();
+
+        for bit in other
+            .into_bytes()
+            .iter()
+            .rev()
+            .flat_map(|byte| (0..8).rev().map(move |i| Choice::from((byte >> i) & 1u8)))
+            .skip(4) // The leading four bits are always unset for Fr.
+        {
+            acc = acc.double();
+            acc = acc + ExtendedNielsPoint::conditional_select(&zero, &base, bit);
+        }
+
+        acc
+    }
+
     /// Computes the doubling of a point more efficiently than a point can
     /// be added to itself.
     pub fn double(&self) -> ExtendedPoint {
@@ -504,26 +761,7 @@ impl<'a, 'b> Mul<&'b Fr> for &'a ExtendedPoint {
     type Output = ExtendedPoint;
 
     fn mul(self, other: &'b Fr) -> ExtendedPoint {
-        let zero = ExtendedPoint::identity().to_niels();
-        let base = self.to_niels();
-
-        let mut acc = ExtendedPoint::identity();
-
-        // This is a simple double-and-add implementation of point
-        // multiplication, moving from most significant to least
-        // significant bit of the scalar.
-        for bit in other
-            .into_bytes()
-            .iter()
-            .rev()
-            .flat_map(|byte| (0..8).rev().map(move |i| Choice::from((byte >> i) & 1u8)))
-            .skip(4) // The leading four bits are always unset for Fr.
-        {
-            acc = acc.double();
-            acc = acc + ExtendedNielsPoint::conditional_select(&zero, &base, bit);
-        }
-
-        acc
+        self.window_table().mul(other)
     }
 }
 
@@ -694,6 +932,20 @@ impl Default for ExtendedPoint {
     }
 }
 
+impl Default for AffineNielsPoint {
+    /// Returns the identity.
+    fn default() -> AffineNielsPoint {
+        AffineNielsPoint::identity()
+    }
+}
+
+impl Default for ExtendedNielsPoint {
+    /// Returns the identity.
+    fn default() -> ExtendedNielsPoint {
+        ExtendedNielsPoint::identity()
+    }
+}
+
 /// This takes a mutable slice of `ExtendedPoint`s and "normalizes" them using
 /// only a single inversion for the entire batch. This normalization results in
 /// all of the points having a Z-coordinate of one. Further, an iterator is
@@ -739,6 +991,112 @@ pub fn batch_normalize<'a>(v: &'a mut [ExtendedPoint]) -> impl Iterator<Item = A
     v.iter().map(|p| AffinePoint { u: p.u, v: p.v })
 }
 
+/// Computes `out[i] = a[i] + b[i]` for every `i`, in affine coordinates and
+/// with a single field inversion shared by the whole batch.
+///
+/// The affine addition formula for Jubjub divides by `1 + d u1 u2 v1 v2` and
+/// `1 - d u1 u2 v1 v2`, which are never zero because `d` is not a square, so
+/// it is complete: the same formula handles doublings and the identity, and
+/// no case needs to fall back to extended coordinates.
+///
+/// This costs 12 multiplications and 2 squarings per element, and a field
+/// inversion. A mixed addition of an `AffineNielsPoint` into an
+/// `ExtendedPoint` costs 8 multiplications, so unlike on short Weierstrass
+/// curves this does not speed up Pippenger's bucket accumulation, and it is
+/// not public until it has a caller that it makes faster.
+///
+/// # Panics
+///
+/// Panics if `a`, `b` and `out` do not all have the same length.
+#[allow(dead_code)]
+pub(crate) fn batch_add_affine(a: &[AffinePoint], b: &[AffinePoint], out: &mut [AffinePoint]) {
+    assert_eq!(a.len(), b.len(), "length mismatch");
+    assert_eq!(a.len(), out.len(), "length mismatch");
+
+    let mut acc = Fq::one();
+    for ((p, q), r) in a.iter().zip(b.iter()).zip(out.iter_mut()) {
+        // We use `out` to store, for each element, the product of the
+        // previous denominators in `u` and `c = d u1 u2 v1 v2` in `v`. The
+        // two denominators `1 + c` and `1 - c` share `c`, and are inverted
+        // together by inverting their product `1 - c^2`.
+        let c = EDWARDS_D * (p.u * q.u) * (p.v * q.v);
+        r.u = acc;
+        r.v = c;
+        acc *= Fq::one() - c.square();
+    }
+
+    // This is the inverse, as all denominators are nonzero. The factor
+    // 1/2 carries through to every `tmp` below, where it cancels the
+    // factor 2 in the numerators.
+    acc = acc.invert_nonzero() * HALF;
+
+    for ((p, q), r) in a.iter().zip(b.iter()).zip(out.iter_mut()).rev() {
+        let c = r.v;
+
+        // Compute tmp = 1 / (2 (1 - c^2))
+        let tmp = acc * r.u;
+
+        // Cancel out this denominator in `acc`
+        acc *= Fq::one() - c.square();
+
+        // x - y = 2 (u1 v2 + v1 u2) and x + y = 2 (v1 v2 + u1 u2)
+        let x = (p.v + p.u) * (q.v + q.u);
+        let y = (p.v - p.u) * (q.v - q.u);
+
+        // (1 - c) tmp and (1 + c) tmp share the product c tmp.
+        let ctmp = c * tmp;
+        r.u = (x - y) * (tmp - ctmp);
+        r.v = (x + y) * (tmp + ctmp);
+    }
+}
+
+/// A point on the curve which is neither of small order nor in the
+/// prime-order subgroup, for use as a base point in tests.
+#[cfg(test)]
+pub(crate) fn test_point() -> ExtendedPoint {
+    ExtendedPoint::from(AffinePoint {
+        u: Fq([
+            0xc0115cb656ae4839,
+            0x623dc3ff81d64c26,
+            0x5868e739b5794f2c,
+            0x23bd4fbb18d39c9c,
+        ]),
+        v: Fq([
+            0x7588ee6d6dd40deb,
+            0x9d6d7a23ebdb7c4c,
+            0x46462e26d4edb8c7,
+            0x10b4c1517ca82e9b,
+        ]),
+    })
+}
+
+/// A scalar with no particular structure, for use in tests.
+#[cfg(test)]
+pub(crate) fn test_scalar() -> Fr {
+    Fr([
+        0x21e61211d9934f2e,
+        0xa52c058a693c3e07,
+        0x9ccb77bfb12d6360,
+        0x07df2470ec94398e,
+    ])
+}
+
+/// Fills `scalars` and `points` with terms for testing multi-scalar
+/// multiplication, derived from `-1` and `test_point()`.
+#[cfg(all(test, feature = "alloc"))]
+pub(crate) fn test_terms(scalars: &mut [Fr], points: &mut [ExtendedPoint]) {
+    let base = test_point();
+
+    let mut scalar = -Fr::one();
+    let mut point = base;
+    for (s, p) in scalars.iter_mut().zip(points.iter_mut()) {
+        *s = scalar;
+        *p = point;
+        scalar = scalar.square() + Fr::one();
+        point = point.double() + base;
+    }
+}
+
 #[test]
 fn test_is_on_curve_var() {
     assert!(AffinePoint::identity().is_on_curve_vartime());
@@ -789,20 +1147,7 @@ fn test_extended_niels_point_identity() {
 
 #[test]
 fn test_assoc() {
-    let p = ExtendedPoint::from(AffinePoint {
-        u: Fq([
-            0xc0115cb656ae4839,
-            0x623dc3ff81d64c26,
-            0x5868e739b5794f2c,
-            0x23bd4fbb18d39c9c,
-        ]),
-        v: Fq([
-            0x7588ee6d6dd40deb,
-            0x9d6d7a23ebdb7c4c,
-            0x46462e26d4edb8c7,
-            0x10b4c1517ca82e9b,
-        ]),
-    }).mul_by_cofactor();
+    let p = test_point().mul_by_cofactor();
     assert!(p.is_on_curve_vartime());
 
     assert_eq!(
@@ -811,23 +1156,10 @@ fn test_assoc() {
     );
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 #[test]
 fn test_batch_normalize() {
-    let mut p = ExtendedPoint::from(AffinePoint {
-        u: Fq([
-            0xc0115cb656ae4839,
-            0x623dc3ff81d64c26,
-            0x5868e739b5794f2c,
-            0x23bd4fbb18d39c9c,
-        ]),
-        v: Fq([
-            0x7588ee6d6dd40deb,
-            0x9d6d7a23ebdb7c4c,
-            0x46462e26d4edb8c7,
-            0x10b4c1517ca82e9b,
-        ]),
-    }).mul_by_cofactor();
+    let mut p = test_point().mul_by_cofactor();
 
     let mut v = vec![];
     for _ in 0..10 {
@@ -839,14 +1171,14 @@ fn test_batch_normalize() {
         assert!(p.is_on_curve_vartime());
     }
 
-    let expected: std::vec::Vec<_> = v.iter().map(|p| AffinePoint::from(*p)).collect();
-    let result1: std::vec::Vec<_> = batch_normalize(&mut v).collect();
+    let expected: alloc::vec::Vec<_> = v.iter().map(|p| AffinePoint::from(*p)).collect();
+    let result1: alloc::vec::Vec<_> = batch_normalize(&mut v).collect();
     for i in 0..10 {
         assert!(expected[i] == result1[i]);
         assert!(v[i].is_on_curve_vartime());
         assert!(AffinePoint::from(v[i]) == expected[i]);
     }
-    let result2: std::vec::Vec<_> = batch_normalize(&mut v).collect();
+    let result2: alloc::vec::Vec<_> = batch_normalize(&mut v).collect();
     for i in 0..10 {
         assert!(expected[i] == result2[i]);
         assert!(v[i].is_on_curve_vartime());
@@ -854,6 +1186,33 @@ fn test_batch_normalize() {
     }
 }
 
+#[test]
+fn test_half() {
+    assert_eq!(HALF, Fq::from(2).invert_nonzero());
+}
+
+#[test]
+fn test_batch_add_affine() {
+    let p = AffinePoint::from(test_point());
+    let q = AffinePoint::from(ExtendedPoint::from(p).double());
+    let id = AffinePoint::identity();
+
+    // This covers distinct points, doublings, the identity on either
+    // side, and a point plus its negation.
+    let a = [p, p, p, id, id, p, q];
+    let b = [q, p, id, q, id, -p, -q];
+    let mut out = [id; 7];
+    batch_add_affine(&a, &b, &mut out);
+
+    for ((a, b), out) in a.iter().zip(b.iter()).zip(out.iter()) {
+        let expected = AffinePoint::from(ExtendedPoint::from(*a) + ExtendedPoint::from(*b));
+        assert_eq!(*out, expected);
+        assert!(out.is_on_curve_vartime());
+    }
+
+    batch_add_affine(&[], &[], &mut []);
+}
+
 #[test]
 fn test_mul_consistency() {
     let a = Fr([
@@ -875,19 +1234,70 @@ fn test_mul_consistency() {
         0x0b677e29380a97a7,
     ]);
     assert_eq!(a * b, c);
-    let p = ExtendedPoint::from(AffinePoint {
-        u: Fq([
-            0xc0115cb656ae4839,
-            0x623dc3ff81d64c26,
-            0x5868e739b5794f2c,
-            0x23bd4fbb18d39c9c,
-        ]),
-        v: Fq([
-            0x7588ee6d6dd40deb,
-            0x9d6d7a23ebdb7c4c,
-            0x46462e26d4edb8c7,
-            0x10b4c1517ca82e9b,
-        ]),
-    }).mul_by_cofactor();
+    let p = test_point().mul_by_cofactor();
     assert_eq!(p * c, (p * a) * b);
 }
+
+#[test]
+fn test_mul_matches_double_and_add() {
+    let p = test_point();
+
+    let mut scalar = test_scalar();
+    for s in &[Fr::zero(), Fr::one(), -Fr::one(), Fr::from(8), Fr::from(0x8888)] {
+        assert_eq!(p * s, p.mul_double_and_add(s));
+    }
+    for _ in 0..20 {
+        assert_eq!(p * scalar, p.mul_double_and_add(&scalar));
+        scalar = scalar.square() + Fr::one();
+    }
+}
+
+#[test]
+fn test_mul_vartime() {
+    let p = test_point();
+
+    let mut scalar = test_scalar();
+    for s in &[Fr::zero(), Fr::one(), -Fr::one(), Fr::from(15), Fr::from(0x8888)] {
+        assert_eq!(p.mul_vartime(s), p * s);
+    }
+    for _ in 0..20 {
+        assert_eq!(p.mul_vartime(&scalar), p * scalar);
+        scalar = scalar.square() + Fr::one();
+    }
+}
+
+#[test]
+fn test_niels_point_negation() {
+    let p = test_point();
+    let q = p.double();
+
+    assert_eq!(q + (-p.to_niels()), q - p);
+    assert_eq!(q + (-AffinePoint::from(p).to_niels()), q - p);
+    assert_eq!(p + (-p.to_niels()), ExtendedPoint::identity());
+}
+
+#[test]
+fn test_from_bytes() {
+    let p = AffinePoint::from(test_point().mul_by_cofactor());
+
+    for p in &[p, -p, AffinePoint::identity()] {
+        let bytes = p.into_bytes();
+        assert_eq!(AffinePoint::from_bytes(bytes).unwrap(), *p);
+        assert_eq!(AffinePoint::from_bytes_vartime(bytes).unwrap(), *p);
+    }
+
+    // v = 2 does not correspond to a point on the curve, in
+    // either the constant time or variable time decoding.
+    let mut bytes = [0u8; 32];
+    bytes[0] = 2;
+    assert!(bool::from(AffinePoint::from_bytes(bytes).is_none()));
+    assert!(AffinePoint::from_bytes_vartime(bytes).is_none());
+
+    // A v-coordinate equal to the modulus is non-canonical.
+    let bytes = [
+        1, 0, 0, 0, 255, 255, 255, 255, 254, 91, 254, 255, 2, 164, 189, 83, 5, 216, 161, 9, 8, 216,
+        57, 51, 72, 125, 157, 41, 83, 167, 237, 115,
+    ];
+    assert!(bool::from(AffinePoint::from_bytes(bytes).is_none()));
+    assert!(AffinePoint::from_bytes_vartime(bytes).is_none());
+}
@@ -0,0 +1,206 @@
+//! A reusable table of small multiples of a point, which can be serialized
+//! so that it outlives the process that computed it.
+
+use core::ops::Mul;
+
+use subtle::{Choice, ConstantTimeEq, CtOption};
+
+use crate::window::WindowTable;
+use crate::{
+    batch_normalize, AffineNielsPoint, AffinePoint, ExtendedPoint, Fq, Fr, EDWARDS_D, EDWARDS_D2,
+    HALF,
+};
+
+/// The multiples `[P, 2P, ..., 8P]` of a point `P` as `AffineNielsPoint`s,
+/// which is the precomputation that `ExtendedPoint`'s `Mul` performs for
+/// every multiplication. Keeping it around makes repeated multiplications
+/// of the same point, such as a public key, cheaper.
+///
+/// The table is 768 bytes when serialized with `into_bytes`.
+/// Multiplication by a table is constant time.
+#[derive(Clone, Copy)]
+pub struct LookupTable(WindowTable<AffineNielsPoint>);
+
+impl LookupTable {
+    /// Precomputes the table for `point`.
+    pub fn new(point: &ExtendedPoint) -> Self {
+        let niels = point.to_niels();
+        let mut multiples = [*point; 8];
+        for j in 1..8 {
+            multiples[j] = multiples[j - 1] + niels;
+        }
+
+        let mut table = [AffineNielsPoint::identity(); 8];
+        for (entry, affine) in table.iter_mut().zip(batch_normalize(&mut multiples)) {
+            *entry = affine.to_niels();
+        }
+
+        LookupTable(WindowTable(table))
+    }
+
+    /// Returns the point `P` this table was computed for.
+    pub fn point(&self) -> ExtendedPoint {
+        ExtendedPoint::identity() + (self.0).0[0]
+    }
+
+    /// Converts this table into its byte representation: the canonical
+    /// little-endian encodings of `v + u`, `v - u` and `2d * u * v` for
+    /// each of `P, 2P, ..., 8P`, in that order.
+    pub fn into_bytes(&self) -> [u8; 768] {
+        let mut bytes = [0u8; 768];
+        for (entry, chunk) in (self.0).0.iter().zip(bytes.chunks_mut(96)) {
+            chunk[0..32].copy_from_slice(&entry.v_plus_u.into_bytes());
+            chunk[32..64].copy_from_slice(&entry.v_minus_u.into_bytes());
+            chunk[64..96].copy_from_slice(&entry.t2d.into_bytes());
+        }
+
+        bytes
+    }
+
+    /// Attempts to interpret a byte representation of a table, failing if
+    /// any element is not canonically encoded, or if the entries are not
+    /// exactly the table of some point on the curve.
+    pub fn from_bytes(bytes: &[u8; 768]) -> CtOption<Self> {
+        let mut valid = Choice::from(1u8);
+        let mut decode = |b: &[u8]| {
+            let mut repr = [0u8; 32];
+            repr.copy_from_slice(b);
+            let x = Fq::from_bytes(repr);
+            valid &= x.is_some();
+            x.unwrap_or(Fq::zero())
+        };
+
+        let mut table = [AffineNielsPoint::identity(); 8];
+        for (entry, chunk) in table.iter_mut().zip(bytes.chunks(96)) {
+            entry.v_plus_u = decode(&chunk[0..32]);
+            entry.v_minus_u = decode(&chunk[32..64]);
+            entry.t2d = decode(&chunk[64..96]);
+        }
+        let table = LookupTable(WindowTable(table));
+
+        // Recover each entry's point (u, v), and check that it is on the
+        // curve, i.e. that -u^2 + v^2 = 1 + d u^2 v^2, and that the entry's
+        // `2d * u * v` matches it.
+        let mut points = [ExtendedPoint::identity(); 8];
+        for (entry, point) in (table.0).0.iter().zip(points.iter_mut()) {
+            let u = (entry.v_plus_u - entry.v_minus_u) * HALF;
+            let v = (entry.v_plus_u + entry.v_minus_u) * HALF;
+            let u2 = u.square();
+            let v2 = v.square();
+            valid &= (v2 - u2).ct_eq(&(Fq::one() + EDWARDS_D * u2 * v2));
+            valid &= entry.t2d.ct_eq(&(u * v * EDWARDS_D2));
+            *point = ExtendedPoint::from(AffinePoint { u, v });
+        }
+
+        // Each entry must be the previous one plus the first. The sums are
+        // compared in projective coordinates, so no inversion is needed.
+        for j in 1..8 {
+            valid &= (points[j - 1] + (table.0).0[0]).ct_eq(&points[j]);
+        }
+
+        CtOption::new(table, valid)
+    }
+}
+
+impl ConstantTimeEq for LookupTable {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        (self.0)
+            .0
+            .iter()
+            .zip((other.0).0.iter())
+            .fold(Choice::from(1u8), |acc, (a, b)| {
+                acc & a.v_plus_u.ct_eq(&b.v_plus_u)
+                    & a.v_minus_u.ct_eq(&b.v_minus_u)
+                    & a.t2d.ct_eq(&b.t2d)
+            })
+    }
+}
+
+impl PartialEq for LookupTable {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl Mul<&Fr> for &LookupTable {
+    type Output = ExtendedPoint;
+
+    /// Computes `a * P` for the point `P` of this table, in the same way as
+    /// `ExtendedPoint`'s `Mul` but without computing the table.
+    fn mul(self, a: &Fr) -> ExtendedPoint {
+        self.0.mul(a)
+    }
+}
+
+#[cfg(test)]
+use crate::{test_point, test_scalar};
+
+#[test]
+fn test_lookup_table_mul() {
+    let p = test_point();
+    let table = LookupTable::new(&p);
+    assert_eq!(table.point(), p);
+
+    let mut scalar = test_scalar();
+    for s in &[Fr::zero(), Fr::one(), -Fr::one(), Fr::from(8)] {
+        assert_eq!(&table * s, p * s);
+    }
+    for _ in 0..10 {
+        assert_eq!(&table * &scalar, p * scalar);
+        scalar = scalar.square() + Fr::one();
+    }
+}
+
+#[test]
+fn test_lookup_table_bytes() {
+    for p in &[
+        test_point(),
+        test_point().double(),
+        ExtendedPoint::identity(),
+    ] {
+        let table = LookupTable::new(p);
+        let bytes = table.into_bytes();
+        let decoded = LookupTable::from_bytes(&bytes).unwrap();
+        assert!(decoded == table);
+        assert_eq!(decoded.into_bytes()[..], bytes[..]);
+    }
+
+    let bytes = LookupTable::new(&test_point()).into_bytes();
+
+    // Swapping two entries gives a table that is not the table of the
+    // point in its first entry.
+    let mut swapped = bytes;
+    swapped[96..192].copy_from_slice(&bytes[192..288]);
+    swapped[192..288].copy_from_slice(&bytes[96..192]);
+    assert!(bool::from(LookupTable::from_bytes(&swapped).is_none()));
+
+    // A table computed consistently from a point that is not on the curve
+    // is rejected.
+    let off_curve = ExtendedPoint::from(AffinePoint {
+        u: Fq::one(),
+        v: Fq::one(),
+    });
+    let bytes_off_curve = LookupTable::new(&off_curve).into_bytes();
+    assert!(bool::from(
+        LookupTable::from_bytes(&bytes_off_curve).is_none()
+    ));
+
+    // Non-canonical field elements are rejected.
+    let mut non_canonical = bytes;
+    for b in non_canonical[64..96].iter_mut() {
+        *b = 0xff;
+    }
+    assert!(bool::from(
+        LookupTable::from_bytes(&non_canonical).is_none()
+    ));
+
+    // So is an entry whose `2d * u * v` belongs to another entry.
+    let mut wrong_t2d = bytes;
+    wrong_t2d[352..384].copy_from_slice(&bytes[256..288]);
+    assert!(bool::from(LookupTable::from_bytes(&wrong_t2d).is_none()));
+
+    // So is a single flipped bit.
+    let mut flipped = bytes;
+    flipped[500] ^= 1;
+    assert!(bool::from(LookupTable::from_bytes(&flipped).is_none()));
+}
@@ -0,0 +1,589 @@
+/// Implements arithmetic for a prime field whose elements are stored as four
+/// 64-bit limbs in Montgomery form, i.e. `$field(a) = aR mod p` with
+/// `R = 2^256`. The modulus must be odd and smaller than `2^255`.
+///
+/// This is how `Fq` and `Fr` are implemented, and it is exported so that
+/// other crates can instantiate sibling fields, such as the BN254 scalar
+/// field, as
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Eq)]
+/// pub struct Fp([u64; 4]);
+///
+/// jubjub::impl_montgomery_field!(Fp, MODULUS, INV, R, R2, R3);
+/// ```
+///
+/// where the arguments after the field name are constants in scope at the
+/// call site: `MODULUS: Fp` holds `p` itself, `INV: u64` is
+/// `-(p^{-1} mod 2^64) mod 2^64`, and `R`, `R2` and `R3: Fp` hold `2^256`,
+/// `2^512` and `2^768` mod `p`. Nothing else needs to be in scope, and the
+/// field type does not need to be `pub`.
+///
+/// This provides the arithmetic operators, `Debug`, `Default`, `From<u64>`,
+/// the `subtle` traits, byte conversions, exponentiation and inversion:
+/// everything that does not depend on the shape of `p - 1`. Square roots and
+/// related functions are left to the caller. `batch_invert` is only provided
+/// when this crate's `alloc` feature is enabled.
+#[macro_export]
+macro_rules! impl_montgomery_field {
+    ($field:ident, $modulus:ident, $inv:ident, $r:ident, $r2:ident, $r3:ident) => {
+        const _: () = {
+            use $crate::__private::byteorder::{ByteOrder, LittleEndian};
+            use $crate::__private::core::fmt;
+            use $crate::__private::core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+            use $crate::__private::subtle::{
+                Choice, ConditionallySelectable, ConstantTimeEq, CtOption,
+            };
+            use $crate::__private::{adc, binary_gcd, mac, safegcd, sbb};
+
+            impl fmt::Debug for $field {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    let tmp = self.into_bytes();
+                    write!(f, "0x")?;
+                    for &b in tmp.iter().rev() {
+                        write!(f, "{:02x}", b)?;
+                    }
+                    Ok(())
+                }
+            }
+
+            impl From<u64> for $field {
+                fn from(val: u64) -> $field {
+                    $field([val, 0, 0, 0]) * $r2
+                }
+            }
+
+            impl ConstantTimeEq for $field {
+                fn ct_eq(&self, other: &Self) -> Choice {
+                    self.0[0].ct_eq(&other.0[0])
+                        & self.0[1].ct_eq(&other.0[1])
+                        & self.0[2].ct_eq(&other.0[2])
+                        & self.0[3].ct_eq(&other.0[3])
+                }
+            }
+
+            impl PartialEq for $field {
+                fn eq(&self, other: &Self) -> bool {
+                    self.ct_eq(other).unwrap_u8() == 1
+                }
+            }
+
+            impl ConditionallySelectable for $field {
+                fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                    $field([
+                        u64::conditional_select(&a.0[0], &b.0[0], choice),
+                        u64::conditional_select(&a.0[1], &b.0[1], choice),
+                        u64::conditional_select(&a.0[2], &b.0[2], choice),
+                        u64::conditional_select(&a.0[3], &b.0[3], choice),
+                    ])
+                }
+            }
+
+            impl<'a> Neg for &'a $field {
+                type Output = $field;
+
+                #[inline]
+                fn neg(self) -> $field {
+                    // Subtract `self` from `MODULUS` to negate. Ignore the final
+                    // borrow because it cannot underflow; self is guaranteed to
+                    // be in the field.
+                    let (d0, borrow) = sbb($modulus.0[0], self.0[0], 0);
+                    let (d1, borrow) = sbb($modulus.0[1], self.0[1], borrow);
+                    let (d2, borrow) = sbb($modulus.0[2], self.0[2], borrow);
+                    let (d3, _) = sbb($modulus.0[3], self.0[3], borrow);
+
+                    // `tmp` could be `MODULUS` if `self` was zero. Create a mask that is
+                    // zero if `self` was zero, and `u64::max_value()` if self was nonzero.
+                    let mask =
+                        u64::from((self.0[0] | self.0[1] | self.0[2] | self.0[3]) == 0).wrapping_sub(1);
+
+                    $field([d0 & mask, d1 & mask, d2 & mask, d3 & mask])
+                }
+            }
+
+            impl Neg for $field {
+                type Output = $field;
+
+                #[inline]
+                fn neg(self) -> $field {
+                    -&self
+                }
+            }
+
+            impl<'a, 'b> Sub<&'b $field> for &'a $field {
+                type Output = $field;
+
+                #[inline]
+                fn sub(self, rhs: &'b $field) -> $field {
+                    let (d0, borrow) = sbb(self.0[0], rhs.0[0], 0);
+                    let (d1, borrow) = sbb(self.0[1], rhs.0[1], borrow);
+                    let (d2, borrow) = sbb(self.0[2], rhs.0[2], borrow);
+                    let (d3, borrow) = sbb(self.0[3], rhs.0[3], borrow);
+
+                    // If underflow occurred on the final limb, borrow = 0xfff...fff, otherwise
+                    // borrow = 0x000...000. Thus, we use it as a mask to conditionally add the modulus.
+                    let (d0, carry) = adc(d0, $modulus.0[0] & borrow, 0);
+                    let (d1, carry) = adc(d1, $modulus.0[1] & borrow, carry);
+                    let (d2, carry) = adc(d2, $modulus.0[2] & borrow, carry);
+                    let (d3, _) = adc(d3, $modulus.0[3] & borrow, carry);
+
+                    $field([d0, d1, d2, d3])
+                }
+            }
+
+            impl<'a, 'b> Add<&'b $field> for &'a $field {
+                type Output = $field;
+
+                #[inline]
+                #[allow(clippy::suspicious_arithmetic_impl)]
+                fn add(self, rhs: &'b $field) -> $field {
+                    let (d0, carry) = adc(self.0[0], rhs.0[0], 0);
+                    let (d1, carry) = adc(self.0[1], rhs.0[1], carry);
+                    let (d2, carry) = adc(self.0[2], rhs.0[2], carry);
+                    let (d3, _) = adc(self.0[3], rhs.0[3], carry);
+
+                    // Attempt to subtract the modulus, to ensure the value
+                    // is smaller than the modulus.
+                    $field([d0, d1, d2, d3]) - &$modulus
+                }
+            }
+
+            impl<'a, 'b> Mul<&'b $field> for &'a $field {
+                type Output = $field;
+
+                #[inline]
+                fn mul(self, rhs: &'b $field) -> $field {
+                    let r = self.mul_wide(rhs);
+
+                    $field::montgomery_reduce(r[0], r[1], r[2], r[3], r[4], r[5], r[6], r[7])
+                }
+            }
+
+            $crate::impl_binops_additive!($field, $field);
+            $crate::impl_binops_multiplicative!($field, $field);
+
+            impl Default for $field {
+                fn default() -> Self {
+                    Self::zero()
+                }
+            }
+
+            impl $field {
+                pub fn zero() -> $field {
+                    $field([0, 0, 0, 0])
+                }
+
+                pub fn one() -> $field {
+                    $r
+                }
+
+                #[inline]
+                pub fn double(&self) -> $field {
+                    self + self
+                }
+
+                /// Attempts to convert a little-endian byte representation of
+                /// a field element into a field element, failing if the input
+                /// is not canonical (is not smaller than the modulus).
+                pub fn from_bytes(bytes: [u8; 32]) -> CtOption<$field> {
+                    let mut tmp = $field([0, 0, 0, 0]);
+
+                    tmp.0[0] = LittleEndian::read_u64(&bytes[0..8]);
+                    tmp.0[1] = LittleEndian::read_u64(&bytes[8..16]);
+                    tmp.0[2] = LittleEndian::read_u64(&bytes[16..24]);
+                    tmp.0[3] = LittleEndian::read_u64(&bytes[24..32]);
+
+                    // Try to subtract the modulus
+                    let (_, borrow) = sbb(tmp.0[0], $modulus.0[0], 0);
+                    let (_, borrow) = sbb(tmp.0[1], $modulus.0[1], borrow);
+                    let (_, borrow) = sbb(tmp.0[2], $modulus.0[2], borrow);
+                    let (_, borrow) = sbb(tmp.0[3], $modulus.0[3], borrow);
+
+                    // If the element is smaller than MODULUS then the
+                    // subtraction will underflow, producing a borrow value
+                    // of 0xffff...ffff. Otherwise, it'll be zero.
+                    let is_some = (borrow as u8) & 1;
+
+                    // Convert to Montgomery form by computing
+                    // (a.R^{-1} * R^2) / R = a.R
+                    tmp.mul_assign(&$r2);
+
+                    CtOption::new(tmp, Choice::from(is_some))
+                }
+
+                /// Attempts to convert a little-endian byte representation of
+                /// a field element into a field element, failing if the input
+                /// is not canonical (is not smaller than the modulus).
+                ///
+                /// **This operation is variable time.**
+                pub fn from_bytes_vartime(bytes: [u8; 32]) -> Option<$field> {
+                    let mut tmp = $field([0, 0, 0, 0]);
+
+                    tmp.0[0] = LittleEndian::read_u64(&bytes[0..8]);
+                    tmp.0[1] = LittleEndian::read_u64(&bytes[8..16]);
+                    tmp.0[2] = LittleEndian::read_u64(&bytes[16..24]);
+                    tmp.0[3] = LittleEndian::read_u64(&bytes[24..32]);
+
+                    // Check if the value is in the field
+                    for i in (0..4).rev() {
+                        if tmp.0[i] < $modulus.0[i] {
+                            // Convert to Montgomery form by computing
+                            // (a.R^{-1} * R^2) / R = a.R
+                            tmp.mul_assign(&$r2);
+
+                            return Some(tmp);
+                        }
+
+                        if tmp.0[i] > $modulus.0[i] {
+                            return None;
+                        }
+                    }
+
+                    // Value is equal to the modulus
+                    None
+                }
+
+                /// Converts a field element into a byte representation in
+                /// little-endian byte order.
+                pub fn into_bytes(&self) -> [u8; 32] {
+                    // Turn into canonical form by computing
+                    // (a.R) / R = a
+                    let tmp =
+                        $field::montgomery_reduce(self.0[0], self.0[1], self.0[2], self.0[3], 0, 0, 0, 0);
+
+                    let mut res = [0; 32];
+                    LittleEndian::write_u64(&mut res[0..8], tmp.0[0]);
+                    LittleEndian::write_u64(&mut res[8..16], tmp.0[1]);
+                    LittleEndian::write_u64(&mut res[16..24], tmp.0[2]);
+                    LittleEndian::write_u64(&mut res[24..32], tmp.0[3]);
+
+                    res
+                }
+
+                pub fn from_bytes_wide(bytes: [u8; 64]) -> $field {
+                    $field::from_u512([
+                        LittleEndian::read_u64(&bytes[0..8]),
+                        LittleEndian::read_u64(&bytes[8..16]),
+                        LittleEndian::read_u64(&bytes[16..24]),
+                        LittleEndian::read_u64(&bytes[24..32]),
+                        LittleEndian::read_u64(&bytes[32..40]),
+                        LittleEndian::read_u64(&bytes[40..48]),
+                        LittleEndian::read_u64(&bytes[48..56]),
+                        LittleEndian::read_u64(&bytes[56..64]),
+                    ])
+                }
+
+                fn from_u512(limbs: [u64; 8]) -> $field {
+                    // We reduce an arbitrary 512-bit number by decomposing it into two 256-bit digits
+                    // with the higher bits multiplied by 2^256. Thus, we perform two reductions
+                    //
+                    // 1. the lower bits are multiplied by R^2, as normal
+                    // 2. the upper bits are multiplied by R^2 * 2^256 = R^3
+                    //
+                    // and computing their sum in the field. It remains to see that arbitrary 256-bit
+                    // numbers can be placed into Montgomery form safely using the reduction. The
+                    // reduction works so long as the product is less than R=2^256 multipled by
+                    // the modulus. This holds because for any `c` smaller than the modulus, we have
+                    // that (2^256 - 1)*c is an acceptable product for the reduction. Therefore, the
+                    // reduction always works so long as `c` is in the field; in this case it is either the
+                    // constant `R2` or `R3`.
+                    let d1 = $field([limbs[4], limbs[5], limbs[6], limbs[7]]) - &$modulus;
+                    let d0 = $field([limbs[0], limbs[1], limbs[2], limbs[3]]) - &$modulus;
+                    // Convert to Montgomery form
+                    d1 * $r3 + d0 * $r2
+                }
+
+                /// Computes the 512-bit product of the Montgomery representations
+                /// of `self` and `rhs`, without reducing it.
+                #[inline]
+                fn mul_wide(&self, rhs: &$field) -> [u64; 8] {
+                    // Schoolbook multiplication
+
+                    let (r0, carry) = mac(0, self.0[0], rhs.0[0], 0);
+                    let (r1, carry) = mac(0, self.0[0], rhs.0[1], carry);
+                    let (r2, carry) = mac(0, self.0[0], rhs.0[2], carry);
+                    let (r3, r4) = mac(0, self.0[0], rhs.0[3], carry);
+
+                    let (r1, carry) = mac(r1, self.0[1], rhs.0[0], 0);
+                    let (r2, carry) = mac(r2, self.0[1], rhs.0[1], carry);
+                    let (r3, carry) = mac(r3, self.0[1], rhs.0[2], carry);
+                    let (r4, r5) = mac(r4, self.0[1], rhs.0[3], carry);
+
+                    let (r2, carry) = mac(r2, self.0[2], rhs.0[0], 0);
+                    let (r3, carry) = mac(r3, self.0[2], rhs.0[1], carry);
+                    let (r4, carry) = mac(r4, self.0[2], rhs.0[2], carry);
+                    let (r5, r6) = mac(r5, self.0[2], rhs.0[3], carry);
+
+                    let (r3, carry) = mac(r3, self.0[3], rhs.0[0], 0);
+                    let (r4, carry) = mac(r4, self.0[3], rhs.0[1], carry);
+                    let (r5, carry) = mac(r5, self.0[3], rhs.0[2], carry);
+                    let (r6, r7) = mac(r6, self.0[3], rhs.0[3], carry);
+
+                    [r0, r1, r2, r3, r4, r5, r6, r7]
+                }
+
+                /// Computes `sum_i a[i] * b[i]`.
+                ///
+                /// Rather than reducing after every multiplication, this sums the
+                /// unreduced 512-bit products in a 576-bit accumulator and reduces
+                /// once at the end, so it costs roughly one multiplication without
+                /// reduction per term.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `a` and `b` have different lengths.
+                pub fn sum_of_products(a: &[$field], b: &[$field]) -> $field {
+                    assert_eq!(a.len(), b.len());
+
+                    // Each product is smaller than p^2 < 2^510, so the accumulator
+                    // cannot overflow for fewer than 2^66 terms.
+                    let mut acc = [0u64; 9];
+                    for (a, b) in a.iter().zip(b.iter()) {
+                        let r = a.mul_wide(b);
+
+                        let mut carry = 0;
+                        for (acc, r) in acc.iter_mut().zip(r.iter()) {
+                            let (sum, c) = adc(*acc, *r, carry);
+                            *acc = sum;
+                            carry = c;
+                        }
+                        acc[8] += carry;
+                    }
+
+                    // We have acc = lo + mid * 2^256 + hi * 2^512 for 256-bit lo
+                    // and mid, and we want acc / R = lo / R + mid + hi * R. These
+                    // are computed respectively by a Montgomery reduction, by
+                    // multiplying mid by R (which is one in Montgomery form, and
+                    // reduces mid below the modulus), and by multiplying hi by R^2.
+                    let lo = $field::montgomery_reduce(acc[0], acc[1], acc[2], acc[3], 0, 0, 0, 0);
+                    let mid = $field([acc[4], acc[5], acc[6], acc[7]]) * $r;
+                    let hi = $field([acc[8], 0, 0, 0]) * $r2;
+
+                    lo + mid + hi
+                }
+
+                /// Squares this element.
+                pub fn square(&self) -> $field {
+                    let (r1, carry) = mac(0, self.0[0], self.0[1], 0);
+                    let (r2, carry) = mac(0, self.0[0], self.0[2], carry);
+                    let (r3, r4) = mac(0, self.0[0], self.0[3], carry);
+
+                    let (r3, carry) = mac(r3, self.0[1], self.0[2], 0);
+                    let (r4, r5) = mac(r4, self.0[1], self.0[3], carry);
+
+                    let (r5, r6) = mac(r5, self.0[2], self.0[3], 0);
+
+                    let r7 = r6 >> 63;
+                    let r6 = (r6 << 1) | (r5 >> 63);
+                    let r5 = (r5 << 1) | (r4 >> 63);
+                    let r4 = (r4 << 1) | (r3 >> 63);
+                    let r3 = (r3 << 1) | (r2 >> 63);
+                    let r2 = (r2 << 1) | (r1 >> 63);
+                    let r1 = r1 << 1;
+
+                    let (r0, carry) = mac(0, self.0[0], self.0[0], 0);
+                    let (r1, carry) = adc(0, r1, carry);
+                    let (r2, carry) = mac(r2, self.0[1], self.0[1], carry);
+                    let (r3, carry) = adc(0, r3, carry);
+                    let (r4, carry) = mac(r4, self.0[2], self.0[2], carry);
+                    let (r5, carry) = adc(0, r5, carry);
+                    let (r6, carry) = mac(r6, self.0[3], self.0[3], carry);
+                    let (r7, _) = adc(0, r7, carry);
+
+                    $field::montgomery_reduce(r0, r1, r2, r3, r4, r5, r6, r7)
+                }
+
+                /// Exponentiates `self` by `by`, where `by` is a
+                /// little-endian order integer exponent.
+                pub fn pow(&self, by: &[u64; 4]) -> Self {
+                    let mut res = Self::one();
+                    for e in by.iter().rev() {
+                        for i in (0..64).rev() {
+                            res = res.square();
+                            let mut tmp = res;
+                            tmp.mul_assign(self);
+                            res.conditional_assign(&tmp, (((*e >> i) & 0x1) as u8).into());
+                        }
+                    }
+                    res
+                }
+
+                /// Exponentiates `self` by `by`, where `by` is a
+                /// little-endian order integer exponent.
+                ///
+                /// **This operation is variable time with respect
+                /// to the exponent.** If the exponent is fixed,
+                /// this operation is effectively constant time.
+                pub fn pow_vartime(&self, by: &[u64; 4]) -> Self {
+                    let mut res = Self::one();
+                    for e in by.iter().rev() {
+                        for i in (0..64).rev() {
+                            res = res.square();
+
+                            if ((*e >> i) & 1) == 1 {
+                                res.mul_assign(self);
+                            }
+                        }
+                    }
+                    res
+                }
+
+                /// Computes the multiplicative inverse of this element,
+                /// failing if the element is zero.
+                pub fn invert(&self) -> CtOption<Self> {
+                    CtOption::new(self.invert_nonzero(), !self.ct_eq(&Self::zero()))
+                }
+
+                /// Inverts every nonzero element of `values` in place, using a single
+                /// field inversion for the entire batch. Zero elements are left as
+                /// zero. Returns a `Choice` that is true if every element was nonzero.
+                ///
+                /// `scratch` is used to store intermediate products and must be at
+                /// least as long as `values`.
+                ///
+                /// This costs 3 multiplications per element, and a field inversion.
+                pub fn batch_invert_with_scratch(values: &mut [$field], scratch: &mut [$field]) -> Choice {
+                    assert!(scratch.len() >= values.len());
+
+                    let mut all_nonzero = Choice::from(1u8);
+
+                    // Store the product of the previous nonzero elements in
+                    // `scratch`, treating zero elements as one.
+                    let mut acc = $field::one();
+                    for (value, tmp) in values.iter().zip(scratch.iter_mut()) {
+                        *tmp = acc;
+
+                        let is_zero = value.ct_eq(&$field::zero());
+                        all_nonzero &= !is_zero;
+                        acc = $field::conditional_select(&(acc * value), &acc, is_zero);
+                    }
+
+                    // This is the inverse, as the product of nonzero elements is nonzero.
+                    acc = acc.invert_nonzero();
+
+                    for (value, tmp) in values.iter_mut().zip(scratch.iter()).rev() {
+                        let is_zero = value.ct_eq(&$field::zero());
+
+                        // Compute 1/value, and cancel out value in the denominator of `acc`
+                        let inverse = acc * tmp;
+                        acc = $field::conditional_select(&(acc * &*value), &acc, is_zero);
+
+                        *value = $field::conditional_select(&inverse, value, is_zero);
+                    }
+
+                    all_nonzero
+                }
+
+                /// Computes the multiplicative inverse of this element,
+                /// failing if the element is zero.
+                ///
+                /// **This operation is variable time.**
+                pub fn invert_vartime(&self) -> Option<Self> {
+                    // Convert out of Montgomery form by computing (a.R) / R = a,
+                    // invert a, and then convert back by computing
+                    // (a^{-1} * R^2) / R = a^{-1}.R
+                    let tmp =
+                        $field::montgomery_reduce(self.0[0], self.0[1], self.0[2], self.0[3], 0, 0, 0, 0);
+
+                    binary_gcd::invert_vartime(&tmp.0, &$modulus.0).map(|inv| $field(inv) * $r2)
+                }
+
+                /// Computes the multiplicative inverse of this element,
+                /// returning zero if the element is zero.
+                pub fn invert_nonzero(&self) -> Self {
+                    // We invert the Montgomery representation aR directly using the
+                    // safegcd algorithm, and then compute
+                    // (aR)^{-1} * R^3 / R = a^{-1}.R to return to Montgomery form.
+                    $field(safegcd::invert(&self.0, &$modulus.0, $inv)) * $r3
+                }
+
+                #[inline]
+                #[allow(clippy::too_many_arguments)]
+                fn montgomery_reduce(
+                    r0: u64,
+                    r1: u64,
+                    r2: u64,
+                    r3: u64,
+                    r4: u64,
+                    r5: u64,
+                    r6: u64,
+                    r7: u64,
+                ) -> Self {
+                    // The Montgomery reduction here is based on Algorithm 14.32 in
+                    // Handbook of Applied Cryptography
+                    // <http://cacr.uwaterloo.ca/hac/about/chap14.pdf>.
+
+                    let k = r0.wrapping_mul($inv);
+                    let (_, carry) = mac(r0, k, $modulus.0[0], 0);
+                    let (r1, carry) = mac(r1, k, $modulus.0[1], carry);
+                    let (r2, carry) = mac(r2, k, $modulus.0[2], carry);
+                    let (r3, carry) = mac(r3, k, $modulus.0[3], carry);
+                    let (r4, carry2) = adc(r4, 0, carry);
+
+                    let k = r1.wrapping_mul($inv);
+                    let (_, carry) = mac(r1, k, $modulus.0[0], 0);
+                    let (r2, carry) = mac(r2, k, $modulus.0[1], carry);
+                    let (r3, carry) = mac(r3, k, $modulus.0[2], carry);
+                    let (r4, carry) = mac(r4, k, $modulus.0[3], carry);
+                    let (r5, carry2) = adc(r5, carry2, carry);
+
+                    let k = r2.wrapping_mul($inv);
+                    let (_, carry) = mac(r2, k, $modulus.0[0], 0);
+                    let (r3, carry) = mac(r3, k, $modulus.0[1], carry);
+                    let (r4, carry) = mac(r4, k, $modulus.0[2], carry);
+                    let (r5, carry) = mac(r5, k, $modulus.0[3], carry);
+                    let (r6, carry2) = adc(r6, carry2, carry);
+
+                    let k = r3.wrapping_mul($inv);
+                    let (_, carry) = mac(r3, k, $modulus.0[0], 0);
+                    let (r4, carry) = mac(r4, k, $modulus.0[1], carry);
+                    let (r5, carry) = mac(r5, k, $modulus.0[2], carry);
+                    let (r6, carry) = mac(r6, k, $modulus.0[3], carry);
+                    let (r7, _) = adc(r7, carry2, carry);
+
+                    // Result may be within MODULUS of the correct value
+                    $field([r4, r5, r6, r7]) - &$modulus
+                }
+            }
+
+            impl<'a> From<&'a $field> for [u8; 32] {
+                fn from(value: &'a $field) -> [u8; 32] {
+                    value.into_bytes()
+                }
+            }
+
+            $crate::__impl_batch_invert!($field);
+        };
+    };
+}
+
+/// Implements `batch_invert` for a field defined by `impl_montgomery_field!`
+/// when the `alloc` feature is enabled. This is a separate macro so that the
+/// feature is checked in this crate rather than in the caller's.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_batch_invert {
+    ($field:ident) => {
+        impl $field {
+            /// Inverts every nonzero element of `values` in place, using a single
+            /// field inversion for the entire batch. Zero elements are left as
+            /// zero. Returns a `Choice` that is true if every element was nonzero.
+            ///
+            /// See `batch_invert_with_scratch` for a version that does not
+            /// allocate.
+            pub fn batch_invert(values: &mut [$field]) -> $crate::__private::subtle::Choice {
+                let mut scratch = $crate::__private::vec![$field::zero(); values.len()];
+
+                $field::batch_invert_with_scratch(values, &mut scratch)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_batch_invert {
+    ($field:ident) => {};
+}
@@ -0,0 +1,338 @@
+//! Multi-scalar multiplication, which computes `sum_i a_i P_i` for many
+//! scalars `a_i` and points `P_i` far faster than one `Mul` per term.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::window::WindowTable;
+use crate::{ExtendedNielsPoint, ExtendedPoint, Fr};
+
+/// Below this many terms, Straus' method with a table of odd multiples per
+/// point beats Pippenger's bucket method.
+#[cfg(feature = "alloc")]
+const PIPPENGER_THRESHOLD: usize = 256;
+
+impl ExtendedPoint {
+    /// Computes `sum_i scalars[i] * points[i]` in constant time, using
+    /// Straus' method: the terms share a single chain of doublings, and for
+    /// each signed radix-16 digit of each scalar a multiple of its point is
+    /// selected from a table in constant time and added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalars` and `points` have different lengths.
+    #[cfg(feature = "alloc")]
+    pub fn multiscalar_mul(scalars: &[Fr], points: &[ExtendedPoint]) -> ExtendedPoint {
+        assert_eq!(scalars.len(), points.len(), "length mismatch");
+
+        let tables: Vec<_> = points.iter().map(|p| p.window_table()).collect();
+        let digits: Vec<_> = scalars.iter().map(|s| s.to_radix_16()).collect();
+
+        straus(&tables, &digits)
+    }
+
+    /// Computes `sum_i scalars[i] * points[i]` in constant time, like
+    /// `multiscalar_mul`, but for a number of terms fixed at compile time,
+    /// so that the tables are kept on the stack and it is available
+    /// without the `alloc` feature.
+    pub fn multiscalar_mul_array<const N: usize>(
+        scalars: &[Fr; N],
+        points: &[ExtendedPoint; N],
+    ) -> ExtendedPoint {
+        let mut tables = [WindowTable([ExtendedNielsPoint::identity(); 8]); N];
+        let mut digits = [[0i8; 64]; N];
+        for i in 0..N {
+            tables[i] = points[i].window_table();
+            digits[i] = scalars[i].to_radix_16();
+        }
+
+        straus(&tables, &digits)
+    }
+
+    /// Computes `sum_i scalars[i] * points[i]`.
+    ///
+    /// This uses Straus' method for small inputs and Pippenger's bucket
+    /// method, with a window size chosen from the number of terms, for
+    /// large ones.
+    ///
+    /// **This operation is variable time.**
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalars` and `points` have different lengths.
+    #[cfg(feature = "alloc")]
+    pub fn multiscalar_mul_vartime(scalars: &[Fr], points: &[ExtendedPoint]) -> ExtendedPoint {
+        assert_eq!(scalars.len(), points.len(), "length mismatch");
+
+        if scalars.len() < PIPPENGER_THRESHOLD {
+            straus_vartime(scalars, points)
+        } else {
+            pippenger_vartime(scalars, points)
+        }
+    }
+}
+
+/// Computes `sum_i digits[i] * P_i`, where `tables[i]` holds the multiples
+/// of `P_i` and `digits[i]` is a scalar in signed radix 16, in constant time.
+fn straus(tables: &[WindowTable<ExtendedNielsPoint>], digits: &[[i8; 64]]) -> ExtendedPoint {
+    let mut acc = ExtendedPoint::identity();
+    for i in (0..64).rev() {
+        if i != 63 {
+            acc = acc.double().double().double().double();
+        }
+        for (table, digits) in tables.iter().zip(digits.iter()) {
+            acc += table.select(digits[i]);
+        }
+    }
+
+    acc
+}
+
+/// Straus' method: the scalars share a single chain of doublings, and each
+/// nonzero digit of their width-5 NAFs adds an odd multiple of its point.
+#[cfg(feature = "alloc")]
+pub(crate) fn straus_vartime(scalars: &[Fr], points: &[ExtendedPoint]) -> ExtendedPoint {
+    let tables: Vec<_> = points.iter().map(|p| p.naf_table()).collect();
+    let nafs: Vec<_> = scalars.iter().map(|s| s.to_wnaf(5)).collect();
+
+    let mut acc = ExtendedPoint::identity();
+    let top = match nafs
+        .iter()
+        .filter_map(|naf| naf.iter().rposition(|digit| *digit != 0))
+        .max()
+    {
+        Some(top) => top,
+        None => return acc,
+    };
+
+    for i in (0..=top).rev() {
+        acc = acc.double();
+        for (naf, table) in nafs.iter().zip(tables.iter()) {
+            let digit = naf[i];
+            if digit > 0 {
+                acc += table.select_vartime(digit as usize);
+            } else if digit < 0 {
+                acc -= table.select_vartime(-digit as usize);
+            }
+        }
+    }
+
+    acc
+}
+
+/// Pippenger's bucket method: each scalar is split into signed `c`-bit
+/// digits, and within each window the points are first sorted into buckets
+/// by digit, so that every point costs one addition per window.
+#[cfg(feature = "alloc")]
+pub(crate) fn pippenger_vartime(scalars: &[Fr], points: &[ExtendedPoint]) -> ExtendedPoint {
+    let c = pippenger_window_size(scalars.len());
+    let num_windows = 252 / c + 1;
+
+    let digits: Vec<_> = scalars.iter().map(|s| signed_digits(s, c)).collect();
+    let points: Vec<_> = points.iter().map(|p| p.to_niels()).collect();
+
+    let mut buckets = alloc::vec![ExtendedPoint::identity(); 1 << (c - 1)];
+    let mut acc = ExtendedPoint::identity();
+    for window in (0..num_windows).rev() {
+        for _ in 0..c {
+            acc = acc.double();
+        }
+
+        for bucket in buckets.iter_mut() {
+            *bucket = ExtendedPoint::identity();
+        }
+        for (digits, point) in digits.iter().zip(points.iter()) {
+            let digit = digits[window];
+            if digit > 0 {
+                let bucket = &mut buckets[(digit - 1) as usize];
+                *bucket += point;
+            } else if digit < 0 {
+                let bucket = &mut buckets[(-digit - 1) as usize];
+                *bucket -= point;
+            }
+        }
+
+        acc += sum_buckets(&buckets);
+    }
+
+    acc
+}
+
+/// Computes `sum_i (i + 1) * buckets[i]` with `2 * buckets.len()` additions,
+/// by summing running totals from the top bucket down.
+#[cfg(feature = "alloc")]
+fn sum_buckets(buckets: &[ExtendedPoint]) -> ExtendedPoint {
+    let mut running = ExtendedPoint::identity();
+    let mut sum = ExtendedPoint::identity();
+    for bucket in buckets.iter().rev() {
+        running += bucket;
+        sum += running;
+    }
+
+    sum
+}
+
+/// Chooses the window size `c` for Pippenger's method over `n` terms, by
+/// minimizing the cost of the additions: each of the `252 / c + 1` windows
+/// costs `n` additions to fill the buckets and `2 * 2^(c - 1)` to sum them.
+/// The latter are weighted double, since they are full rather than mixed
+/// additions and the buckets fall out of cache as `c` grows; this matches
+/// the measured optimum better than counting additions alone.
+#[cfg(feature = "alloc")]
+fn pippenger_window_size(n: usize) -> usize {
+    (2..=16)
+        .min_by_key(|c| (252 / c + 1) * (n + (2 << c)))
+        .unwrap()
+}
+
+/// Writes the scalar in radix `2^c` with signed digits, returning
+/// `252 / c + 1` digits `a_j` such that the scalar is `sum_j a_j 2^(c j)`
+/// and `-2^(c - 1) <= a_j <= 2^(c - 1)`.
+#[cfg(feature = "alloc")]
+fn signed_digits(scalar: &Fr, c: usize) -> Vec<i32> {
+    let bytes = scalar.into_bytes();
+    let num_windows = 252 / c + 1;
+    let radix = 1i32 << c;
+
+    let mut digits = Vec::with_capacity(num_windows);
+    let mut carry = 0;
+    for window in 0..num_windows {
+        // Read the c bits starting at bit c * window.
+        let mut bits = 0;
+        for i in 0..c {
+            let bit = c * window + i;
+            if bit < 256 {
+                bits |= i32::from((bytes[bit / 8] >> (bit % 8)) & 1) << i;
+            }
+        }
+
+        // Recenter the digit from [0, 2^c] to [-2^(c - 1), 2^(c - 1)).
+        // The top digit is not recentered, and since the scalar is less
+        // than 2^252 it is at most 2^(c - 1).
+        let mut digit = bits + carry;
+        carry = 0;
+        if window + 1 < num_windows && digit >= radix / 2 {
+            digit -= radix;
+            carry = 1;
+        }
+        digits.push(digit);
+    }
+
+    digits
+}
+
+#[cfg(all(test, feature = "alloc"))]
+use crate::test_terms;
+
+#[cfg(all(test, feature = "alloc"))]
+fn naive_msm(scalars: &[Fr], points: &[ExtendedPoint]) -> ExtendedPoint {
+    let mut acc = ExtendedPoint::identity();
+    for (scalar, point) in scalars.iter().zip(points.iter()) {
+        acc += point * scalar;
+    }
+    acc
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_signed_digits() {
+    let mut scalars = [Fr::zero(); 10];
+    test_terms(&mut scalars[2..], &mut [ExtendedPoint::identity(); 8]);
+    scalars[1] = Fr::one();
+
+    for c in 2..=16 {
+        for scalar in scalars.iter() {
+            let digits = signed_digits(scalar, c);
+
+            let mut acc = Fr::zero();
+            for digit in digits.iter().rev() {
+                for _ in 0..c {
+                    acc = acc.double();
+                }
+                let abs = Fr::from(u64::from(digit.unsigned_abs()));
+                acc += if *digit < 0 { -abs } else { abs };
+            }
+            assert_eq!(acc, *scalar);
+
+            for digit in digits.iter() {
+                assert!(digit.unsigned_abs() <= 1 << (c - 1));
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_multiscalar_mul_array() {
+    let mut scalars = [Fr::zero(); 17];
+    let mut points = [ExtendedPoint::identity(); 17];
+    test_terms(&mut scalars, &mut points);
+    scalars[3] = Fr::zero();
+
+    assert_eq!(
+        ExtendedPoint::multiscalar_mul_array(&[], &[]),
+        ExtendedPoint::identity()
+    );
+    assert_eq!(
+        ExtendedPoint::multiscalar_mul_array(&[scalars[0]], &[points[0]]),
+        ExtendedPoint::multiscalar_mul(&scalars[..1], &points[..1])
+    );
+    assert_eq!(
+        ExtendedPoint::multiscalar_mul_array(&scalars, &points),
+        ExtendedPoint::multiscalar_mul(&scalars, &points)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_multiscalar_mul() {
+    let mut scalars = [Fr::zero(); 33];
+    let mut points = [ExtendedPoint::identity(); 33];
+    test_terms(&mut scalars, &mut points);
+    scalars[5] = Fr::zero();
+
+    for n in &[0, 1, 2, 7, 33] {
+        let (scalars, points) = (&scalars[..*n], &points[..*n]);
+        let expected = naive_msm(scalars, points);
+
+        assert_eq!(ExtendedPoint::multiscalar_mul(scalars, points), expected);
+        assert_eq!(
+            ExtendedPoint::multiscalar_mul_vartime(scalars, points),
+            expected
+        );
+        assert_eq!(straus_vartime(scalars, points), expected);
+        assert_eq!(pippenger_vartime(scalars, points), expected);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_multiscalar_mul_vartime_large() {
+    let mut scalars = [Fr::zero(); PIPPENGER_THRESHOLD + 1];
+    let mut points = [ExtendedPoint::identity(); PIPPENGER_THRESHOLD + 1];
+    test_terms(&mut scalars, &mut points);
+    assert_eq!(
+        ExtendedPoint::multiscalar_mul_vartime(&scalars, &points),
+        straus_vartime(&scalars, &points)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic]
+fn test_multiscalar_mul_length_mismatch() {
+    let mut scalars = [Fr::zero(); 3];
+    let mut points = [ExtendedPoint::identity(); 3];
+    test_terms(&mut scalars, &mut points);
+    ExtendedPoint::multiscalar_mul(&scalars, &points[..2]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic]
+fn test_multiscalar_mul_vartime_length_mismatch() {
+    let mut scalars = [Fr::zero(); 3];
+    let mut points = [ExtendedPoint::identity(); 3];
+    test_terms(&mut scalars, &mut points);
+    ExtendedPoint::multiscalar_mul_vartime(&scalars, &points[..2]);
+}
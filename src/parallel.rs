@@ -0,0 +1,220 @@
+//! Multithreaded versions of batch operations over many points, which split
+//! their input into contiguous chunks handled by scoped worker threads.
+//!
+//! The chunking depends only on the input length and the number of threads,
+//! and partial results are combined in chunk order, so the output is the
+//! same as that of the single-threaded operation.
+
+use std::thread;
+use std::vec::Vec;
+
+use crate::{batch_normalize, AffinePoint, ExtendedPoint, Fr};
+
+/// The smallest number of points worth normalizing on a separate thread;
+/// below this, spawning the thread costs more than the work.
+const MIN_NORMALIZE_CHUNK: usize = 4096;
+
+/// The smallest number of terms worth giving to a separate thread in a
+/// multi-scalar multiplication.
+const MIN_MSM_CHUNK: usize = 64;
+
+/// Returns the number of threads available to this process.
+fn num_threads() -> usize {
+    thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// Returns the size of the chunks to split `len` items into across at most
+/// `threads` threads, with no chunk smaller than `min_chunk` unless there
+/// is only one.
+fn chunk_size(len: usize, min_chunk: usize, threads: usize) -> usize {
+    let chunks = (len / min_chunk).min(threads).max(1);
+    (len + chunks - 1) / chunks
+}
+
+/// This is a multithreaded version of [`batch_normalize`](crate::batch_normalize),
+/// which normalizes chunks of `v` in parallel, with one field inversion per
+/// chunk.
+pub fn batch_normalize_parallel<'a>(
+    v: &'a mut [ExtendedPoint],
+) -> impl Iterator<Item = AffinePoint> + 'a {
+    normalize_with_threads(v, num_threads());
+
+    // All extended points are now normalized, but the type
+    // doesn't encode this fact. Let us offer affine points
+    // to the caller.
+    v.iter().map(|p| AffinePoint { u: p.u, v: p.v })
+}
+
+fn normalize_with_threads(v: &mut [ExtendedPoint], threads: usize) {
+    if v.is_empty() {
+        return;
+    }
+
+    let chunk = chunk_size(v.len(), MIN_NORMALIZE_CHUNK, threads);
+    thread::scope(|s| {
+        for points in v.chunks_mut(chunk) {
+            s.spawn(move || {
+                // batch_normalize updates the points before returning.
+                let _ = batch_normalize(points);
+            });
+        }
+    });
+}
+
+impl ExtendedPoint {
+    /// This is a multithreaded version of
+    /// [`multiscalar_mul`](crate::ExtendedPoint::multiscalar_mul), which
+    /// splits the terms into chunks, multiplies each chunk on its own thread
+    /// and adds the partial results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalars` and `points` have different lengths.
+    pub fn multiscalar_mul_parallel(scalars: &[Fr], points: &[ExtendedPoint]) -> ExtendedPoint {
+        msm_with_threads(
+            scalars,
+            points,
+            num_threads(),
+            ExtendedPoint::multiscalar_mul,
+        )
+    }
+
+    /// This is a multithreaded version of
+    /// [`multiscalar_mul_vartime`](crate::ExtendedPoint::multiscalar_mul_vartime),
+    /// which splits the terms into chunks, multiplies each chunk on its own
+    /// thread and adds the partial results.
+    ///
+    /// **This operation is variable time.**
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalars` and `points` have different lengths.
+    pub fn multiscalar_mul_vartime_parallel(
+        scalars: &[Fr],
+        points: &[ExtendedPoint],
+    ) -> ExtendedPoint {
+        msm_with_threads(
+            scalars,
+            points,
+            num_threads(),
+            ExtendedPoint::multiscalar_mul_vartime,
+        )
+    }
+}
+
+fn msm_with_threads(
+    scalars: &[Fr],
+    points: &[ExtendedPoint],
+    threads: usize,
+    msm: fn(&[Fr], &[ExtendedPoint]) -> ExtendedPoint,
+) -> ExtendedPoint {
+    assert_eq!(scalars.len(), points.len(), "length mismatch");
+    if scalars.is_empty() {
+        return ExtendedPoint::identity();
+    }
+
+    let chunk = chunk_size(scalars.len(), MIN_MSM_CHUNK, threads);
+    let partials: Vec<ExtendedPoint> = thread::scope(|s| {
+        let handles: Vec<_> = scalars
+            .chunks(chunk)
+            .zip(points.chunks(chunk))
+            .map(|(scalars, points)| s.spawn(move || msm(scalars, points)))
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    partials
+        .iter()
+        .fold(ExtendedPoint::identity(), |acc, partial| acc + partial)
+}
+
+#[cfg(test)]
+use crate::{test_terms, Fq};
+
+#[test]
+fn test_chunk_size() {
+    assert_eq!(chunk_size(0, 64, 8), 0);
+    assert_eq!(chunk_size(10, 64, 8), 10);
+    assert_eq!(chunk_size(128, 64, 8), 64);
+    assert_eq!(chunk_size(1000, 64, 8), 125);
+    assert_eq!(chunk_size(1001, 64, 8), 126);
+    assert_eq!(chunk_size(1000, 64, 1), 1000);
+}
+
+#[test]
+fn test_batch_normalize_parallel() {
+    let mut points = vec![ExtendedPoint::identity(); 3 * MIN_NORMALIZE_CHUNK + 5];
+    test_terms(&mut vec![Fr::zero(); points.len()], &mut points);
+
+    let mut expected = points.clone();
+    let expected: Vec<_> = batch_normalize(&mut expected).collect();
+
+    for threads in &[1, 2, 4] {
+        let mut v = points.clone();
+        normalize_with_threads(&mut v, *threads);
+        for ((p, q), r) in v.iter().zip(points.iter()).zip(expected.iter()) {
+            assert_eq!(p, q);
+            assert_eq!(p.z, Fq::one());
+            assert_eq!(AffinePoint::from(*p), *r);
+        }
+    }
+
+    let mut v = points.clone();
+    assert_eq!(
+        batch_normalize_parallel(&mut v).collect::<Vec<_>>(),
+        expected
+    );
+    assert_eq!(batch_normalize_parallel(&mut []).count(), 0);
+}
+
+#[test]
+fn test_multiscalar_mul_parallel() {
+    let mut scalars = vec![Fr::zero(); 4 * MIN_MSM_CHUNK + 3];
+    let mut points = vec![ExtendedPoint::identity(); scalars.len()];
+    test_terms(&mut scalars, &mut points);
+    let expected = ExtendedPoint::multiscalar_mul_vartime(&scalars, &points);
+
+    for threads in &[1, 3, 4] {
+        assert_eq!(
+            msm_with_threads(&scalars, &points, *threads, ExtendedPoint::multiscalar_mul),
+            expected
+        );
+        assert_eq!(
+            msm_with_threads(
+                &scalars,
+                &points,
+                *threads,
+                ExtendedPoint::multiscalar_mul_vartime
+            ),
+            expected
+        );
+    }
+
+    assert_eq!(
+        ExtendedPoint::multiscalar_mul_parallel(&scalars, &points),
+        expected
+    );
+    assert_eq!(
+        ExtendedPoint::multiscalar_mul_vartime_parallel(&scalars, &points),
+        expected
+    );
+    assert_eq!(
+        ExtendedPoint::multiscalar_mul_vartime_parallel(&[], &[]),
+        ExtendedPoint::identity()
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_multiscalar_mul_parallel_length_mismatch() {
+    let mut scalars = [Fr::zero(); 3];
+    let mut points = [ExtendedPoint::identity(); 3];
+    test_terms(&mut scalars, &mut points);
+    ExtendedPoint::multiscalar_mul_parallel(&scalars, &points[..2]);
+}
@@ -0,0 +1,386 @@
+//! Univariate polynomials over prime fields in dense coefficient form.
+
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Neg, Sub};
+
+use crate::domain::EvaluationDomain;
+use crate::field::PrimeField;
+
+/// Products whose factors both have at least this many coefficients are
+/// computed with number-theoretic transforms when the field supports a
+/// large enough evaluation domain, and with schoolbook multiplication
+/// otherwise.
+const NTT_MUL_THRESHOLD: usize = 32;
+
+/// A polynomial `a_0 + a_1 X + ... + a_d X^d`, stored as its coefficients
+/// in order of increasing degree. The representation is normalized so that
+/// the leading coefficient is nonzero; the zero polynomial has no
+/// coefficients.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DensePolynomial<F: PrimeField> {
+    coeffs: Vec<F>,
+}
+
+impl<F: PrimeField> DensePolynomial<F> {
+    /// Returns the zero polynomial.
+    pub fn zero() -> Self {
+        DensePolynomial { coeffs: Vec::new() }
+    }
+
+    /// Constructs a polynomial from its coefficients, in order of
+    /// increasing degree. Trailing zero coefficients are removed.
+    pub fn from_coefficients_vec(coeffs: Vec<F>) -> Self {
+        let mut result = DensePolynomial { coeffs };
+        result.normalize();
+        result
+    }
+
+    /// Constructs a polynomial from its coefficients, in order of
+    /// increasing degree. Trailing zero coefficients are removed.
+    pub fn from_coefficients_slice(coeffs: &[F]) -> Self {
+        Self::from_coefficients_vec(coeffs.to_vec())
+    }
+
+    /// Returns the coefficients of this polynomial in order of increasing
+    /// degree, with no trailing zeros.
+    pub fn coeffs(&self) -> &[F] {
+        &self.coeffs
+    }
+
+    /// Consumes this polynomial, returning its coefficients.
+    pub fn into_coeffs(self) -> Vec<F> {
+        self.coeffs
+    }
+
+    /// Returns true iff this is the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// Returns the degree of this polynomial. The zero polynomial is
+    /// considered to have degree zero.
+    pub fn degree(&self) -> usize {
+        self.coeffs.len().saturating_sub(1)
+    }
+
+    /// Evaluates this polynomial at `point` using Horner's rule.
+    pub fn evaluate(&self, point: &F) -> F {
+        let mut result = F::zero();
+        for coeff in self.coeffs.iter().rev() {
+            result *= point;
+            result += coeff;
+        }
+        result
+    }
+
+    /// Returns the formal derivative of this polynomial.
+    pub fn derivative(&self) -> Self {
+        let mut coeffs = Vec::with_capacity(self.degree());
+        let mut i = F::zero();
+        for coeff in self.coeffs.iter().skip(1) {
+            i += &F::one();
+            coeffs.push(*coeff * &i);
+        }
+
+        Self::from_coefficients_vec(coeffs)
+    }
+
+    /// Multiplies every coefficient of this polynomial by `scalar`.
+    pub fn scale(&self, scalar: &F) -> Self {
+        Self::from_coefficients_vec(self.coeffs.iter().map(|c| *c * scalar).collect())
+    }
+
+    /// Computes the product of this polynomial and `other` by schoolbook
+    /// multiplication.
+    pub fn mul_schoolbook(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+
+        let mut coeffs = vec![F::zero(); self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in other.coeffs.iter().enumerate() {
+                coeffs[i + j] += *a * b;
+            }
+        }
+
+        Self::from_coefficients_vec(coeffs)
+    }
+
+    /// Computes the product of this polynomial and `other` by evaluating
+    /// both over a radix-2 domain, multiplying pointwise and interpolating.
+    /// Returns `None` if the field has no domain large enough to hold the
+    /// product.
+    pub fn mul_ntt(&self, other: &Self) -> Option<Self> {
+        if self.is_zero() || other.is_zero() {
+            return Some(Self::zero());
+        }
+
+        let len = self.coeffs.len() + other.coeffs.len() - 1;
+        let domain = EvaluationDomain::<F>::with_min_size(len as u64)?;
+        let size = domain.size() as usize;
+
+        let mut a = self.coeffs.clone();
+        a.resize(size, F::zero());
+        let mut b = other.coeffs.clone();
+        b.resize(size, F::zero());
+
+        domain.ntt(&mut a);
+        domain.ntt(&mut b);
+        for (a, b) in a.iter_mut().zip(b.iter()) {
+            *a *= b;
+        }
+        domain.intt(&mut a);
+        a.truncate(len);
+
+        Some(Self::from_coefficients_vec(a))
+    }
+
+    /// Divides this polynomial by `divisor` using long division, returning
+    /// the quotient and remainder, where the remainder has degree less than
+    /// that of `divisor`. Returns `None` if `divisor` is zero.
+    pub fn divide_with_remainder(&self, divisor: &Self) -> Option<(Self, Self)> {
+        let leading = divisor.coeffs.last()?;
+
+        if self.coeffs.len() < divisor.coeffs.len() {
+            return Some((Self::zero(), self.clone()));
+        }
+
+        let leading_inv = leading.invert_nonzero();
+        let mut remainder = self.coeffs.clone();
+        let mut quotient = vec![F::zero(); self.coeffs.len() - divisor.coeffs.len() + 1];
+
+        for i in (0..quotient.len()).rev() {
+            let q = remainder[i + divisor.degree()] * &leading_inv;
+            for (r, d) in remainder[i..].iter_mut().zip(divisor.coeffs.iter()) {
+                *r -= q * d;
+            }
+            quotient[i] = q;
+        }
+
+        remainder.truncate(divisor.degree());
+
+        Some((
+            Self::from_coefficients_vec(quotient),
+            Self::from_coefficients_vec(remainder),
+        ))
+    }
+
+    /// Divides this polynomial by the vanishing polynomial `X^n - 1` of
+    /// `domain`, returning the quotient and remainder. This takes linear
+    /// time, using `X^n = 1` modulo the vanishing polynomial.
+    pub fn divide_by_vanishing_poly(&self, domain: &EvaluationDomain<F>) -> (Self, Self) {
+        let n = domain.size() as usize;
+        if self.coeffs.len() <= n {
+            return (Self::zero(), self.clone());
+        }
+
+        let mut remainder = self.coeffs.clone();
+        let mut quotient = vec![F::zero(); self.coeffs.len() - n];
+
+        for i in (n..remainder.len()).rev() {
+            let q = remainder[i];
+            quotient[i - n] = q;
+            remainder[i - n] += q;
+        }
+
+        remainder.truncate(n);
+
+        (
+            Self::from_coefficients_vec(quotient),
+            Self::from_coefficients_vec(remainder),
+        )
+    }
+
+    fn normalize(&mut self) {
+        while let Some(true) = self.coeffs.last().map(|c| bool::from(c.is_zero())) {
+            self.coeffs.pop();
+        }
+    }
+}
+
+impl<F: PrimeField> Add<&DensePolynomial<F>> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn add(self, rhs: &DensePolynomial<F>) -> DensePolynomial<F> {
+        let (longer, shorter) = if self.coeffs.len() >= rhs.coeffs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+
+        let mut coeffs = longer.coeffs.clone();
+        for (a, b) in coeffs.iter_mut().zip(shorter.coeffs.iter()) {
+            *a += b;
+        }
+
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+}
+
+impl<F: PrimeField> Sub<&DensePolynomial<F>> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn sub(self, rhs: &DensePolynomial<F>) -> DensePolynomial<F> {
+        let mut coeffs = self.coeffs.clone();
+        if coeffs.len() < rhs.coeffs.len() {
+            coeffs.resize(rhs.coeffs.len(), F::zero());
+        }
+        for (a, b) in coeffs.iter_mut().zip(rhs.coeffs.iter()) {
+            *a -= b;
+        }
+
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+}
+
+impl<F: PrimeField> Mul<&DensePolynomial<F>> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    /// Multiplies two polynomials, using number-theoretic transforms for
+    /// large inputs when the field supports them (as `Fq` does) and
+    /// schoolbook multiplication otherwise.
+    fn mul(self, rhs: &DensePolynomial<F>) -> DensePolynomial<F> {
+        if self.coeffs.len().min(rhs.coeffs.len()) >= NTT_MUL_THRESHOLD {
+            if let Some(product) = self.mul_ntt(rhs) {
+                return product;
+            }
+        }
+
+        self.mul_schoolbook(rhs)
+    }
+}
+
+impl<F: PrimeField> Neg for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn neg(self) -> DensePolynomial<F> {
+        DensePolynomial {
+            coeffs: self.coeffs.iter().map(|c| -*c).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::{Fq, Fr};
+
+#[cfg(test)]
+fn test_poly<F: PrimeField>(len: usize, seed: u64) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_vec(
+        (0..len as u64)
+            .map(|i| F::from(i * i + seed).square() + F::from(seed))
+            .collect(),
+    )
+}
+
+#[test]
+fn test_normalize_and_degree() {
+    let p = DensePolynomial::from_coefficients_slice(&[Fq::one(), Fq::zero(), Fq::zero()]);
+    assert_eq!(p.coeffs(), &[Fq::one()]);
+    assert_eq!(p.degree(), 0);
+
+    let zero = DensePolynomial::from_coefficients_slice(&[Fq::zero(), Fq::zero()]);
+    assert!(zero.is_zero());
+    assert_eq!(zero, DensePolynomial::zero());
+
+    let a = test_poly::<Fq>(5, 1);
+    assert!((&a - &a).is_zero());
+    assert!((&a + &(-&a)).is_zero());
+}
+
+#[test]
+fn test_add_sub_evaluate() {
+    let a = test_poly::<Fr>(7, 1);
+    let b = test_poly::<Fr>(3, 2);
+    let x = Fr::from(12345);
+
+    assert_eq!((&a + &b).evaluate(&x), a.evaluate(&x) + b.evaluate(&x));
+    assert_eq!((&b + &a).evaluate(&x), a.evaluate(&x) + b.evaluate(&x));
+    assert_eq!((&a - &b).evaluate(&x), a.evaluate(&x) - b.evaluate(&x));
+    assert_eq!((&b - &a).evaluate(&x), b.evaluate(&x) - a.evaluate(&x));
+    assert_eq!(a.scale(&x).evaluate(&x), a.evaluate(&x) * x);
+}
+
+#[test]
+fn test_mul() {
+    let x = Fq::from(777);
+    for &(m, n) in &[(0, 5), (1, 1), (3, 8), (32, 32), (40, 100), (100, 40)] {
+        let a = test_poly::<Fq>(m, 3);
+        let b = test_poly::<Fq>(n, 4);
+
+        let product = a.mul_schoolbook(&b);
+        assert_eq!(product.evaluate(&x), a.evaluate(&x) * b.evaluate(&x));
+        assert_eq!(a.mul_ntt(&b).unwrap(), product);
+        assert_eq!(&a * &b, product);
+    }
+
+    // Fr only has domains of size 2, so large products fall back to
+    // schoolbook multiplication.
+    let a = test_poly::<Fr>(40, 5);
+    let b = test_poly::<Fr>(40, 6);
+    assert!(a.mul_ntt(&b).is_none());
+    assert_eq!(&a * &b, a.mul_schoolbook(&b));
+}
+
+#[test]
+fn test_divide_with_remainder() {
+    for &(m, n) in &[(10, 3), (3, 10), (20, 20), (17, 1)] {
+        let a = test_poly::<Fq>(m, 7);
+        let b = test_poly::<Fq>(n, 8);
+
+        let (q, r) = a.divide_with_remainder(&b).unwrap();
+        assert!(r.is_zero() || r.degree() < b.degree());
+        assert_eq!(&(&q * &b) + &r, a);
+    }
+
+    let a = test_poly::<Fr>(10, 7);
+    let b = test_poly::<Fr>(4, 8);
+    let (q, r) = (&a * &b).divide_with_remainder(&b).unwrap();
+    assert_eq!(q, a);
+    assert!(r.is_zero());
+
+    assert!(a.divide_with_remainder(&DensePolynomial::zero()).is_none());
+}
+
+#[test]
+fn test_divide_by_vanishing_poly() {
+    let domain = EvaluationDomain::<Fq>::new(3).unwrap();
+    let mut vanishing = vec![Fq::zero(); 9];
+    vanishing[0] = -Fq::one();
+    vanishing[8] = Fq::one();
+    let vanishing = DensePolynomial::from_coefficients_vec(vanishing);
+
+    for &len in &[0, 5, 8, 9, 30] {
+        let a = test_poly::<Fq>(len, 9);
+        assert_eq!(
+            a.divide_by_vanishing_poly(&domain),
+            a.divide_with_remainder(&vanishing).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_derivative() {
+    // d/dX (1 + 2X + 3X^2 + 4X^3) = 2 + 6X + 12X^2
+    let p = DensePolynomial::from_coefficients_vec(vec![
+        Fr::from(1),
+        Fr::from(2),
+        Fr::from(3),
+        Fr::from(4),
+    ]);
+    assert_eq!(
+        p.derivative().coeffs(),
+        &[Fr::from(2), Fr::from(6), Fr::from(12)]
+    );
+    assert!(DensePolynomial::from_coefficients_slice(&[Fr::from(5)])
+        .derivative()
+        .is_zero());
+
+    // Product rule
+    let a = test_poly::<Fq>(6, 1);
+    let b = test_poly::<Fq>(4, 2);
+    assert_eq!(
+        (&a * &b).derivative(),
+        &(&a.derivative() * &b) + &(&a * &b.derivative())
+    );
+}
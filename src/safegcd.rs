@@ -0,0 +1,257 @@
+//! Constant-time modular inversion based on the "safegcd" divstep algorithm
+//! of Bernstein and Yang, "Fast constant-time gcd computation and modular
+//! inversion" <https://eprint.iacr.org/2019/266>.
+//!
+//! This follows the approach taken by `libsecp256k1` (`modinv64_impl.h`),
+//! which is described in detail at
+//! <https://github.com/bitcoin-core/secp256k1/blob/master/doc/safegcd_implementation.md>.
+//! Numbers are represented as five signed 62-bit limbs, and divsteps are
+//! performed in batches of 59 on the low limbs only, with the resulting
+//! transition matrix applied to the full-width values afterwards.
+
+/// Mask for the low 62 bits of a limb.
+const M62: u64 = (1 << 62) - 1;
+
+/// A signed integer represented as `v[0] + v[1] * 2^62 + ... + v[4] * 2^248`,
+/// where the lower four limbs are usually in `[0, 2^62)` and the top limb
+/// carries the sign.
+type Signed62 = [i64; 5];
+
+/// The transition matrix `[[u, v], [q, r]]` of a batch of divsteps, scaled by
+/// `2^62`.
+struct Transition {
+    u: i64,
+    v: i64,
+    q: i64,
+    r: i64,
+}
+
+fn to_signed62(a: &[u64; 4]) -> Signed62 {
+    [
+        (a[0] & M62) as i64,
+        ((a[0] >> 62 | a[1] << 2) & M62) as i64,
+        ((a[1] >> 60 | a[2] << 4) & M62) as i64,
+        ((a[2] >> 58 | a[3] << 6) & M62) as i64,
+        (a[3] >> 56) as i64,
+    ]
+}
+
+fn from_signed62(a: &Signed62) -> [u64; 4] {
+    let a = [a[0] as u64, a[1] as u64, a[2] as u64, a[3] as u64, a[4] as u64];
+
+    [
+        a[0] | a[1] << 62,
+        a[1] >> 2 | a[2] << 60,
+        a[2] >> 4 | a[3] << 58,
+        a[3] >> 6 | a[4] << 56,
+    ]
+}
+
+/// Performs 59 divsteps on the low 64 bits of `f` and `g`, starting from
+/// `zeta = -(delta + 1/2)`, and returns the new `zeta` together with the
+/// transition matrix that was applied.
+fn divsteps_59(mut zeta: i64, f0: u64, g0: u64) -> (i64, Transition) {
+    // The matrix starts as the identity scaled by 2^3 and accumulates one
+    // factor of 2 per divstep, ending up scaled by 2^62.
+    let (mut u, mut v, mut q, mut r) = (8u64, 0u64, 0u64, 8u64);
+    let (mut f, mut g) = (f0, g0);
+
+    for _ in 3..62 {
+        // mask1 is all ones if zeta < 0 (that is, delta > 0), and mask2 is
+        // all ones if g is odd.
+        let mut mask1 = (zeta >> 63) as u64;
+        let mask2 = (g & 1).wrapping_neg();
+
+        // Conditionally negate f, u and v, and add them to g, q and r if
+        // g is odd.
+        let x = (f ^ mask1).wrapping_sub(mask1);
+        let y = (u ^ mask1).wrapping_sub(mask1);
+        let z = (v ^ mask1).wrapping_sub(mask1);
+        g = g.wrapping_add(x & mask2);
+        q = q.wrapping_add(y & mask2);
+        r = r.wrapping_add(z & mask2);
+
+        // If both delta > 0 and g was odd, this was a swapping step; the
+        // old g is recovered by adding the new g to f.
+        mask1 &= mask2;
+        zeta = (zeta ^ mask1 as i64) - 1;
+        f = f.wrapping_add(g & mask1);
+        u = u.wrapping_add(q & mask1);
+        v = v.wrapping_add(r & mask1);
+
+        // g is now even, so halve it; we double (u, v) instead of halving
+        // (q, r) to keep the matrix integral.
+        g >>= 1;
+        u <<= 1;
+        v <<= 1;
+    }
+
+    (
+        zeta,
+        Transition {
+            u: u as i64,
+            v: v as i64,
+            q: q as i64,
+            r: r as i64,
+        },
+    )
+}
+
+/// Computes `(t * [d, e]) / 2^62 mod m`, adding a multiple of the modulus
+/// so that the division is exact. Keeps `d` and `e` in `(-2m, m)`.
+fn update_de(d: &mut Signed62, e: &mut Signed62, t: &Transition, m: &Signed62, m_inv62: u64) {
+    let (u, v, q, r) = (
+        i128::from(t.u),
+        i128::from(t.v),
+        i128::from(t.q),
+        i128::from(t.r),
+    );
+
+    // Start md, me at zero; add [u, q] if d is negative and [v, r] if e
+    // is negative, to keep the result in range.
+    let sd = d[4] >> 63;
+    let se = e[4] >> 63;
+    let mut md = (t.u & sd) + (t.v & se);
+    let mut me = (t.q & sd) + (t.r & se);
+
+    let mut cd = u * i128::from(d[0]) + v * i128::from(e[0]);
+    let mut ce = q * i128::from(d[0]) + r * i128::from(e[0]);
+
+    // Correct md, me so that t * [d, e] + m * [md, me] has 62 zero low bits.
+    md -= (m_inv62.wrapping_mul(cd as u64).wrapping_add(md as u64) & M62) as i64;
+    me -= (m_inv62.wrapping_mul(ce as u64).wrapping_add(me as u64) & M62) as i64;
+
+    cd += i128::from(m[0]) * i128::from(md);
+    ce += i128::from(m[0]) * i128::from(me);
+    debug_assert_eq!(cd as u64 & M62, 0);
+    debug_assert_eq!(ce as u64 & M62, 0);
+    cd >>= 62;
+    ce >>= 62;
+
+    for i in 1..5 {
+        cd += u * i128::from(d[i]) + v * i128::from(e[i]) + i128::from(m[i]) * i128::from(md);
+        ce += q * i128::from(d[i]) + r * i128::from(e[i]) + i128::from(m[i]) * i128::from(me);
+        d[i - 1] = (cd as u64 & M62) as i64;
+        e[i - 1] = (ce as u64 & M62) as i64;
+        cd >>= 62;
+        ce >>= 62;
+    }
+
+    d[4] = cd as i64;
+    e[4] = ce as i64;
+}
+
+/// Computes `(t * [f, g]) / 2^62`, which is exact by construction.
+fn update_fg(f: &mut Signed62, g: &mut Signed62, t: &Transition) {
+    let (u, v, q, r) = (
+        i128::from(t.u),
+        i128::from(t.v),
+        i128::from(t.q),
+        i128::from(t.r),
+    );
+
+    let mut cf = u * i128::from(f[0]) + v * i128::from(g[0]);
+    let mut cg = q * i128::from(f[0]) + r * i128::from(g[0]);
+    debug_assert_eq!(cf as u64 & M62, 0);
+    debug_assert_eq!(cg as u64 & M62, 0);
+    cf >>= 62;
+    cg >>= 62;
+
+    for i in 1..5 {
+        cf += u * i128::from(f[i]) + v * i128::from(g[i]);
+        cg += q * i128::from(f[i]) + r * i128::from(g[i]);
+        f[i - 1] = (cf as u64 & M62) as i64;
+        g[i - 1] = (cg as u64 & M62) as i64;
+        cf >>= 62;
+        cg >>= 62;
+    }
+
+    f[4] = cf as i64;
+    g[4] = cg as i64;
+}
+
+/// Brings `a` from `(-2m, m)` into `[0, m)`, negating it first if `sign`
+/// is negative.
+fn normalize(a: &mut Signed62, sign: i64, m: &Signed62) {
+    // Add the modulus if the input is negative, then conditionally negate,
+    // which leaves us in (-m, m).
+    let cond_add = a[4] >> 63;
+    for i in 0..5 {
+        a[i] += m[i] & cond_add;
+    }
+    let cond_negate = sign >> 63;
+    for limb in a.iter_mut() {
+        *limb = (*limb ^ cond_negate) - cond_negate;
+    }
+    propagate_carries(a);
+
+    // Add the modulus again if the result is still negative.
+    let cond_add = a[4] >> 63;
+    for i in 0..5 {
+        a[i] += m[i] & cond_add;
+    }
+    propagate_carries(a);
+}
+
+fn propagate_carries(a: &mut Signed62) {
+    for i in 0..4 {
+        a[i + 1] += a[i] >> 62;
+        a[i] &= M62 as i64;
+    }
+}
+
+/// Computes `a^{-1} mod m` in constant time, returning zero if `a` is zero.
+///
+/// The modulus `m` must be odd and smaller than `2^255`, `a` must be smaller
+/// than `m`, and `inv` must be `-(m^{-1} mod 2^64) mod 2^64`, which is the
+/// `INV` constant used for Montgomery reduction.
+pub fn invert(a: &[u64; 4], m: &[u64; 4], inv: u64) -> [u64; 4] {
+    let modulus = to_signed62(m);
+    let m_inv62 = inv.wrapping_neg() & M62;
+
+    let mut d = [0, 0, 0, 0, 0];
+    let mut e = [1, 0, 0, 0, 0];
+    let mut f = modulus;
+    let mut g = to_signed62(a);
+    let mut zeta = -1;
+
+    // 10 batches of 59 divsteps is 590 divsteps, which suffices for any
+    // input when the modulus is smaller than 2^256.
+    for _ in 0..10 {
+        let (new_zeta, t) = divsteps_59(zeta, f[0] as u64, g[0] as u64);
+        zeta = new_zeta;
+        update_de(&mut d, &mut e, &t, &modulus, m_inv62);
+        update_fg(&mut f, &mut g, &t);
+    }
+
+    // g is now zero and f is +/- gcd(a, m) = +/- 1 (when a is nonzero), so
+    // d is +/- the inverse depending on the sign of f.
+    normalize(&mut d, f[4], &modulus);
+
+    from_signed62(&d)
+}
+
+#[test]
+fn test_signed62_round_trip() {
+    let a = [
+        0xd0970e5ed6f72cb7,
+        0xa6682093ccc81082,
+        0x06673b0101343b00,
+        0x0e7db4ea6533afa9,
+    ];
+
+    assert_eq!(from_signed62(&to_signed62(&a)), a);
+}
+
+#[test]
+fn test_invert_small() {
+    // 2^{-1} mod 7 = 4, and -(7^{-1}) mod 2^64 is 0x9249249249249249.
+    let m = [7, 0, 0, 0];
+    let inv = 0x9249249249249249u64;
+    assert_eq!(7u64.wrapping_mul(inv), !0);
+
+    assert_eq!(invert(&[0, 0, 0, 0], &m, inv), [0, 0, 0, 0]);
+    assert_eq!(invert(&[1, 0, 0, 0], &m, inv), [1, 0, 0, 0]);
+    assert_eq!(invert(&[2, 0, 0, 0], &m, inv), [4, 0, 0, 0]);
+    assert_eq!(invert(&[6, 0, 0, 0], &m, inv), [6, 0, 0, 0]);
+}
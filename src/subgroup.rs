@@ -0,0 +1,249 @@
+//! Points that are known to lie in the prime-order subgroup of Jubjub.
+
+use core::convert::TryFrom;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use crate::generator_table::GENERATOR;
+use crate::{AffinePoint, ExtendedPoint, Fq, Fr};
+
+/// This represents a point in the prime-order subgroup of Jubjub, of order
+/// `r`, in extended coordinates.
+///
+/// Jubjub has cofactor `8`, so an `ExtendedPoint` may have a small-order
+/// component. A `SubgroupPoint` can only be constructed from a point that
+/// has been checked to be torsion-free, and its arithmetic never leaves the
+/// subgroup, so code that takes one does not need to check it again.
+#[derive(Clone, Copy, Debug)]
+pub struct SubgroupPoint(ExtendedPoint);
+
+impl SubgroupPoint {
+    /// Constructs the identity.
+    pub fn identity() -> Self {
+        SubgroupPoint(ExtendedPoint::identity())
+    }
+
+    /// Returns the standard generator of the subgroup. This is `8` times the
+    /// point of order `8r` with the smallest `v` coordinate, `v = 11`, and
+    /// even `u`, as derived by `doc/derive/generator.py`.
+    ///
+    /// `BasepointTable::generator` is a precomputed table for it.
+    pub fn generator() -> Self {
+        SubgroupPoint(ExtendedPoint::from(AffinePoint {
+            u: Fq(GENERATOR.0),
+            v: Fq(GENERATOR.1),
+        }))
+    }
+
+    /// Attempts to interpret `point` as an element of the subgroup, failing
+    /// if it is not torsion-free.
+    pub fn from_extended(point: ExtendedPoint) -> CtOption<Self> {
+        CtOption::new(SubgroupPoint(point), point.is_torsion_free())
+    }
+
+    /// Attempts to interpret a byte representation of an affine point as an
+    /// element of the subgroup, failing if it is not a valid encoding of a
+    /// point or if the point is not torsion-free.
+    pub fn from_bytes(b: [u8; 32]) -> CtOption<Self> {
+        AffinePoint::from_bytes(b).and_then(|p| SubgroupPoint::from_extended(p.into()))
+    }
+
+    /// Converts this element into its byte representation.
+    pub fn into_bytes(&self) -> [u8; 32] {
+        AffinePoint::from(self.0).into_bytes()
+    }
+
+    /// Returns true if this element is the identity.
+    pub fn is_identity(&self) -> Choice {
+        self.0.is_identity()
+    }
+
+    /// Computes the doubling of this element.
+    pub fn double(&self) -> SubgroupPoint {
+        SubgroupPoint(self.0.double())
+    }
+}
+
+impl TryFrom<ExtendedPoint> for SubgroupPoint {
+    type Error = ();
+
+    /// Fails if `point` is not torsion-free.
+    fn try_from(point: ExtendedPoint) -> Result<Self, ()> {
+        Option::from(SubgroupPoint::from_extended(point)).ok_or(())
+    }
+}
+
+impl TryFrom<AffinePoint> for SubgroupPoint {
+    type Error = ();
+
+    /// Fails if `point` is not torsion-free.
+    fn try_from(point: AffinePoint) -> Result<Self, ()> {
+        SubgroupPoint::try_from(ExtendedPoint::from(point))
+    }
+}
+
+impl From<SubgroupPoint> for ExtendedPoint {
+    fn from(point: SubgroupPoint) -> ExtendedPoint {
+        point.0
+    }
+}
+
+impl<'a> From<&'a SubgroupPoint> for ExtendedPoint {
+    fn from(point: &'a SubgroupPoint) -> ExtendedPoint {
+        point.0
+    }
+}
+
+impl From<SubgroupPoint> for AffinePoint {
+    /// Constructs an affine point from a subgroup point. This requires a
+    /// field inversion.
+    fn from(point: SubgroupPoint) -> AffinePoint {
+        AffinePoint::from(point.0)
+    }
+}
+
+impl ConstantTimeEq for SubgroupPoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl PartialEq for SubgroupPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).unwrap_u8() == 1
+    }
+}
+
+impl ConditionallySelectable for SubgroupPoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        SubgroupPoint(ExtendedPoint::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl Default for SubgroupPoint {
+    /// Returns the identity.
+    fn default() -> SubgroupPoint {
+        SubgroupPoint::identity()
+    }
+}
+
+impl Neg for SubgroupPoint {
+    type Output = SubgroupPoint;
+
+    #[inline]
+    fn neg(self) -> SubgroupPoint {
+        SubgroupPoint(-self.0)
+    }
+}
+
+impl Add<&SubgroupPoint> for &SubgroupPoint {
+    type Output = SubgroupPoint;
+
+    #[inline]
+    fn add(self, other: &SubgroupPoint) -> SubgroupPoint {
+        SubgroupPoint(self.0 + other.0)
+    }
+}
+
+impl Sub<&SubgroupPoint> for &SubgroupPoint {
+    type Output = SubgroupPoint;
+
+    #[inline]
+    fn sub(self, other: &SubgroupPoint) -> SubgroupPoint {
+        SubgroupPoint(self.0 - other.0)
+    }
+}
+
+impl_binops_additive!(SubgroupPoint, SubgroupPoint);
+
+impl Mul<&Fr> for &SubgroupPoint {
+    type Output = SubgroupPoint;
+
+    fn mul(self, other: &Fr) -> SubgroupPoint {
+        SubgroupPoint(self.0 * other)
+    }
+}
+
+impl_binops_multiplicative!(SubgroupPoint, Fr);
+
+#[cfg(test)]
+use crate::generator_table::FULL_GENERATOR;
+#[cfg(test)]
+use crate::test_point;
+
+#[test]
+fn test_torsion() {
+    // (0, -1) is the point of order 2.
+    let t2 = ExtendedPoint::from(AffinePoint {
+        u: Fq::zero(),
+        v: -Fq::one(),
+    });
+    assert!(bool::from(t2.is_small_order()));
+    assert!(!bool::from(t2.is_torsion_free()));
+    assert!(!bool::from(t2.is_identity()));
+
+    let identity = ExtendedPoint::identity();
+    assert!(bool::from(identity.is_identity()));
+    assert!(bool::from(identity.is_small_order()));
+    assert!(bool::from(identity.is_torsion_free()));
+
+    // The test point has a small-order component, which the cofactor
+    // clears.
+    let p = test_point();
+    assert!(!bool::from(p.is_small_order()));
+    assert!(!bool::from(p.is_torsion_free()));
+
+    let q = p.mul_by_cofactor();
+    assert!(!bool::from(q.is_small_order()));
+    assert!(bool::from(q.is_torsion_free()));
+    assert!(!bool::from((q + t2).is_torsion_free()));
+}
+
+#[test]
+fn test_subgroup_point() {
+    let p = test_point();
+    let q = p.mul_by_cofactor();
+
+    assert!(SubgroupPoint::try_from(p).is_err());
+    assert!(SubgroupPoint::try_from(AffinePoint::from(p)).is_err());
+    assert!(bool::from(SubgroupPoint::from_extended(p).is_none()));
+
+    let a = SubgroupPoint::try_from(q).unwrap();
+    assert_eq!(a, SubgroupPoint::try_from(AffinePoint::from(q)).unwrap());
+    assert_eq!(a, SubgroupPoint::from_extended(q).unwrap());
+    assert_eq!(ExtendedPoint::from(a), q);
+    assert_eq!(AffinePoint::from(a), AffinePoint::from(q));
+
+    let s = Fr::from(0x1234_5678);
+    let b = a * s;
+    assert_eq!(ExtendedPoint::from(b), q * s);
+    assert_eq!(ExtendedPoint::from(a + b), q + q * s);
+    assert_eq!(ExtendedPoint::from(a - b), q - q * s);
+    assert_eq!(ExtendedPoint::from(-a), -q);
+    assert_eq!(a.double(), a + a);
+    assert!(bool::from((a - a).is_identity()));
+    assert!(bool::from(ExtendedPoint::from(a + b).is_torsion_free()));
+
+    // Serialization round-trips, and rejects points outside the subgroup.
+    assert_eq!(SubgroupPoint::from_bytes(a.into_bytes()).unwrap(), a);
+    assert!(bool::from(
+        SubgroupPoint::from_bytes(AffinePoint::from(p).into_bytes()).is_none()
+    ));
+}
+
+#[test]
+fn test_generator() {
+    let full = ExtendedPoint::from(AffinePoint {
+        u: Fq(FULL_GENERATOR.0),
+        v: Fq(FULL_GENERATOR.1),
+    });
+    assert_eq!(AffinePoint::from(full).v, Fq::from(11));
+    assert!(!bool::from(full.is_small_order()));
+    assert!(!bool::from(full.is_torsion_free()));
+
+    let g = SubgroupPoint::generator();
+    assert_eq!(ExtendedPoint::from(g), full.mul_by_cofactor());
+    assert!(!bool::from(g.is_identity()));
+    assert!(bool::from(ExtendedPoint::from(g).is_torsion_free()));
+}
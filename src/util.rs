@@ -19,6 +19,8 @@ pub fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
     (ret as u64, (ret >> 64) as u64)
 }
 
+#[doc(hidden)]
+#[macro_export]
 macro_rules! impl_binops_additive {
     ($lhs:ident, $rhs:ident) => {
         impl<'b> Sub<&'b $rhs> for $lhs {
@@ -105,6 +107,8 @@ macro_rules! impl_binops_additive {
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
 macro_rules! impl_binops_multiplicative {
     ($lhs:ident, $rhs:ident) => {
         impl<'b> Mul<&'b $rhs> for $lhs {
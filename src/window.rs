@@ -0,0 +1,113 @@
+//! Lookup tables of small multiples of a point, for windowed scalar
+//! multiplication with signed digits.
+
+use core::ops::{AddAssign, Neg};
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+use crate::{ExtendedPoint, Fr};
+
+#[cfg(feature = "alloc")]
+use crate::AffineNielsPoint;
+
+/// A table of the multiples `[P, 2P, ..., 8P]` of a point `P`, from which
+/// any multiple `xP` with `-8 <= x <= 8` can be selected in constant time.
+#[derive(Clone, Copy)]
+pub(crate) struct WindowTable<T>(pub(crate) [T; 8]);
+
+impl<T> WindowTable<T>
+where
+    T: Copy + Default + ConditionallySelectable + Neg<Output = T>,
+{
+    /// Returns `xP`, where `Default::default()` is taken to be the
+    /// identity. This does not branch on or index memory by `x`.
+    pub(crate) fn select(&self, x: i8) -> T {
+        debug_assert!((-8..=8).contains(&x));
+
+        // Compute xabs = |x|
+        let xmask = x >> 7;
+        let xabs = (x + xmask) ^ xmask;
+
+        // Set t = 0 * P = identity
+        let mut t = T::default();
+        for j in 1..=8 {
+            // Copy `points[j-1] == j*P` onto `t` in constant time if `|x| == j`.
+            let c = (xabs as u8).ct_eq(&(j as u8));
+            t.conditional_assign(&self.0[j - 1], c);
+        }
+
+        // Now t == |x| * P. Negate it if x was negative.
+        let neg_mask = ((xmask & 1) as u8).into();
+
+        T::conditional_select(&t, &-t, neg_mask)
+    }
+}
+
+impl<T> WindowTable<T>
+where
+    T: Copy + Default + ConditionallySelectable + Neg<Output = T>,
+    ExtendedPoint: AddAssign<T>,
+{
+    /// Computes `aP` in constant time. This is a fixed-window
+    /// multiplication using the signed radix-16 digits of `a`, moving from
+    /// the most significant to the least significant digit. Each digit
+    /// costs four doublings and one addition of a multiple of `P`, which is
+    /// selected from the table in constant time.
+    pub(crate) fn mul(&self, a: &Fr) -> ExtendedPoint {
+        let digits = a.to_radix_16();
+
+        let mut acc = ExtendedPoint::identity();
+        acc += self.select(digits[63]);
+        for digit in digits[..63].iter().rev() {
+            acc = acc.double().double().double().double();
+            acc += self.select(*digit);
+        }
+
+        acc
+    }
+}
+
+/// A table of the odd multiples `[P, 3P, 5P, ..., 15P]` of a point `P`, for
+/// scalar multiplication with width-5 NAF digits.
+#[derive(Clone, Copy)]
+pub(crate) struct NafLookupTable5<T>(pub(crate) [T; 8]);
+
+impl<T: Copy> NafLookupTable5<T> {
+    /// Returns `xP` for odd `0 < x < 16`.
+    ///
+    /// **This operation is variable time.**
+    pub(crate) fn select_vartime(&self, x: usize) -> T {
+        debug_assert!(x & 1 == 1 && x < 16);
+        self.0[x / 2]
+    }
+}
+
+/// A table of the odd multiples `[P, 3P, 5P, ..., 127P]` of a point `P`,
+/// for scalar multiplication with width-8 NAF digits.
+#[derive(Clone, Copy)]
+pub(crate) struct NafLookupTable8<T>(pub(crate) [T; 64]);
+
+impl<T: Copy> NafLookupTable8<T> {
+    /// Returns `xP` for odd `0 < x < 128`.
+    ///
+    /// **This operation is variable time.**
+    pub(crate) fn select_vartime(&self, x: usize) -> T {
+        debug_assert!(x & 1 == 1 && x < 128);
+        self.0[x / 2]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl WindowTable<AffineNielsPoint> {
+    /// Returns `acc + xP`.
+    ///
+    /// **This operation is variable time.**
+    pub(crate) fn add_vartime(&self, acc: &ExtendedPoint, x: i8) -> ExtendedPoint {
+        if x > 0 {
+            acc + &self.0[(x - 1) as usize]
+        } else if x < 0 {
+            acc - &self.0[(-x - 1) as usize]
+        } else {
+            *acc
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Instantiates `impl_montgomery_field!` outside of this crate for the BN254
+//! scalar field, to check that the macro is not specialized to the moduli of
+//! `Fq` and `Fr` and needs nothing in scope beyond its arguments.
+
+#[derive(Clone, Copy, Eq)]
+struct Fp([u64; 4]);
+
+// p = 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+const MODULUS: Fp = Fp([
+    0x43e1f593f0000001,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
+
+const INV: u64 = 0xc2e1f593efffffff;
+
+const R: Fp = Fp([
+    0xac96341c4ffffffb,
+    0x36fc76959f60cd29,
+    0x666ea36f7879462e,
+    0x0e0a77c19a07df2f,
+]);
+
+const R2: Fp = Fp([
+    0x1bb8e645ae216da7,
+    0x53fe3ab1e35c59e3,
+    0x8c49833d53bb8085,
+    0x0216d0b17f4e44a5,
+]);
+
+const R3: Fp = Fp([
+    0x5e94d8e1b4bf0040,
+    0x2a489cbe1cfbb6b8,
+    0x893cc664a19fcfed,
+    0x0cf8594b7fcc657c,
+]);
+
+jubjub::impl_montgomery_field!(Fp, MODULUS, INV, R, R2, R3);
+
+#[test]
+fn test_arithmetic() {
+    let minus_one = [
+        0, 0, 0, 240, 147, 245, 225, 67, 145, 112, 185, 121, 72, 232, 51, 40, 93, 88, 129, 129,
+        182, 69, 80, 184, 41, 160, 49, 225, 114, 78, 100, 48,
+    ];
+    assert_eq!((-Fp::one()).into_bytes(), minus_one);
+    assert_eq!(Fp::from_bytes(minus_one).unwrap(), -Fp::one());
+    assert_eq!((-Fp::one()).square(), Fp::one());
+
+    let mut modulus = minus_one;
+    modulus[0] = 1;
+    assert!(bool::from(Fp::from_bytes(modulus).is_none()));
+
+    // 5^{-1} mod p
+    let five_inv = Fp([
+        0xe7f3fbd4c6666667,
+        0xa9ae5ce9ca4a2d06,
+        0x49b9b57c33cd568b,
+        0x135b52945a13d9aa,
+    ]) * R2;
+    assert_eq!(Fp::from(5).invert().unwrap(), five_inv);
+    assert_eq!(Fp::from(5).invert_vartime().unwrap(), five_inv);
+    assert_eq!(five_inv * Fp::from(5), Fp::one());
+
+    let a = Fp::from_bytes_wide([0xff; 64]);
+    assert_eq!(a.invert_nonzero() * a, Fp::one());
+    assert_eq!(a.pow(&[5, 0, 0, 0]), a.square().square() * a);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_batch_invert() {
+    let mut values = [Fp::from(5), Fp::zero(), Fp::from(7)];
+    assert!(!bool::from(Fp::batch_invert(&mut values)));
+    assert_eq!(values[0], Fp::from(5).invert().unwrap());
+    assert_eq!(values[1], Fp::zero());
+    assert_eq!(values[2], Fp::from(7).invert().unwrap());
+}
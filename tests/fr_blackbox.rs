@@ -118,3 +118,63 @@ fn test_multiply_additive_identity() {
         assert_eq!(Fr::zero(), a * Fr::zero());
     }
 }
+
+#[test]
+fn test_sqrt_matches_vartime() {
+    let mut rng = new_rng();
+    for _ in 0..NUM_BLACK_BOX_CHECKS {
+        let a = Fr::new_random(&mut rng);
+        assert_eq!(Option::<Fr>::from(a.sqrt()).is_some(), a.sqrt_vartime().is_some());
+        assert_eq!(a.square().sqrt().unwrap().square(), a.square());
+    }
+}
+
+#[test]
+fn test_invert_matches_invert_nonzero() {
+    let mut rng = new_rng();
+    assert!(bool::from(Fr::zero().invert().is_none()));
+    for _ in 0..NUM_BLACK_BOX_CHECKS {
+        let a = Fr::new_random(&mut rng);
+        assert_eq!(a.invert().unwrap(), a.invert_nonzero());
+    }
+}
+
+#[test]
+fn test_invert_vartime_matches_invert_nonzero() {
+    let mut rng = new_rng();
+    assert!(Fr::zero().invert_vartime().is_none());
+    for _ in 0..NUM_BLACK_BOX_CHECKS {
+        let a = Fr::new_random(&mut rng);
+        assert_eq!(a.invert_vartime().unwrap(), a.invert_nonzero());
+    }
+}
+
+#[test]
+fn test_batch_invert_matches_invert() {
+    let mut rng = new_rng();
+    let mut values = [Fr::zero(); 64];
+    let mut scratch = [Fr::zero(); 64];
+    for _ in 0..(NUM_BLACK_BOX_CHECKS / 64) {
+        for value in values.iter_mut() {
+            *value = Fr::new_random(&mut rng);
+        }
+        let expected: Vec<_> = values.iter().map(|v| v.invert().unwrap()).collect();
+        assert!(bool::from(Fr::batch_invert_with_scratch(&mut values, &mut scratch)));
+        assert_eq!(&values[..], &expected[..]);
+    }
+}
+
+#[test]
+fn test_sum_of_products() {
+    let mut rng = new_rng();
+    for len in 0..NUM_BLACK_BOX_CHECKS as usize / 100 {
+        let a: Vec<Fr> = (0..len).map(|_| Fr::new_random(&mut rng)).collect();
+        let b: Vec<Fr> = (0..len).map(|_| Fr::new_random(&mut rng)).collect();
+
+        let mut expected = Fr::zero();
+        for (a, b) in a.iter().zip(b.iter()) {
+            expected += a * b;
+        }
+        assert_eq!(Fr::sum_of_products(&a, &b), expected);
+    }
+}